@@ -1,5 +1,10 @@
+use std::{cell::RefCell, rc::Rc};
+
 use egui::{vec2, CentralPanel, Context};
-use egui_dialogs::{dialog_window, Dialog, DialogContext, DialogDetails, Dialogs, StandardReply};
+use egui_dialogs::{
+    dialog_window, Dialog, DialogContext, DialogDetails, DialogPresentation, Dialogs,
+    StandardDialog, StandardReply, WizardDialog, WizardPage,
+};
 
 fn main() -> Result<(), eframe::Error> {
     // Create native window
@@ -100,42 +105,42 @@ impl eframe::App for DialogApp<'_> {
         const CLOSE_CONFIRM_DIALOG_ID: &str = "close_confirm_dialog";
         const NAME_CONFIRM_DIALOG_ID: &str = "name_confirm_dialog";
         const NAME_INPUT_CONFIRM_DIALOG_ID: &str = "name_input_confirm_dialog";
+        const FORGET_NAME_DIALOG_ID: &str = "forget_name_dialog";
+        const RENAME_DIALOG_ID: &str = "rename_dialog";
+        const WIZARD_DIALOG_ID: &str = "wizard_dialog";
 
         // Show dialogs and handle the reply if there is one
         if let Some(res) = self.dialogs.show(ctx) {
             if res.is_reply_of(CLOSE_CONFIRM_DIALOG_ID) {
-                match res.reply() {
-                    Ok(StandardReply::Yes) => {
-                        self.allow_to_close = true;
-                        ctx.send_viewport_cmd(egui::ViewportCommand::Close);
-                    }
-                    _ => {}
+                if let Ok(StandardReply::Yes) = res.reply() {
+                    self.allow_to_close = true;
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Close);
                 }
             } else if res.is_reply_of(NAME_CONFIRM_DIALOG_ID) {
-                match res.reply() {
-                    Ok(StandardReply::No) => {
-                        self.confirmed_name = "".into();
-                    }
-                    _ => {}
+                if let Ok(StandardReply::No) = res.reply() {
+                    self.confirmed_name = "".into();
                 }
             } else if res.is_reply_of(NAME_INPUT_CONFIRM_DIALOG_ID) {
-                match res.reply() {
-                    Ok(name) => {
-                        self.confirmed_name = name;
-                    }
-                    _ => {}
+                if let Ok(name) = res.reply() {
+                    self.confirmed_name = name;
+                }
+            } else if res.is_reply_of(RENAME_DIALOG_ID) || res.is_reply_of(WIZARD_DIALOG_ID) {
+                if let Ok(Some(name)) = res.reply() {
+                    self.confirmed_name = name;
+                }
+            } else if res.is_reply_of(FORGET_NAME_DIALOG_ID) {
+                if let Ok(StandardReply::Yes) = res.reply() {
+                    self.confirmed_name = "".into();
                 }
             }
         }
 
-        if ctx.input(|i| i.viewport().close_requested()) {
-            if !self.allow_to_close {
-                ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
-                self.dialogs
-                    .confirm("Close", "Are you sure you want to close the window?", |d| {
-                        d.with_id(CLOSE_CONFIRM_DIALOG_ID)
-                    });
-            }
+        if ctx.input(|i| i.viewport().close_requested()) && !self.allow_to_close {
+            ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+            self.dialogs
+                .confirm("Close", "Are you sure you want to close the window?", |d| {
+                    d.with_id(CLOSE_CONFIRM_DIALOG_ID)
+                });
         }
 
         CentralPanel::default().show(ctx, |ui| {
@@ -174,6 +179,21 @@ impl eframe::App for DialogApp<'_> {
                 }
             });
 
+            // Show standard dialogs as sheets and drawers
+            ui.horizontal(|ui| {
+                if ui.button("Bottom sheet").clicked() {
+                    DialogDetails::info(self.title.clone(), self.content.clone())
+                        .with_presentation(DialogPresentation::BottomSheet)
+                        .show(&mut self.dialogs);
+                }
+
+                if ui.button("Side drawer").clicked() {
+                    DialogDetails::info(self.title.clone(), self.content.clone())
+                        .with_presentation(DialogPresentation::Drawer(egui::panel::Side::Left))
+                        .show(&mut self.dialogs);
+                }
+            });
+
             ui.horizontal(|ui| {
                 // Show standard confirm dialog with id
                 if ui.button("Show confirm dialog").clicked() {
@@ -191,10 +211,60 @@ impl eframe::App for DialogApp<'_> {
                         .with_id(NAME_INPUT_CONFIRM_DIALOG_ID)
                         .show_if_absent(&mut self.dialogs);
                 }
+
+                // Show standard dialog with custom widgets in its body
+                if ui.button("Rename").clicked() {
+                    let name = Rc::new(RefCell::new(self.confirmed_name.clone()));
+                    let dialog = StandardDialog::confirm("Rename", "").with_body({
+                        let name = Rc::clone(&name);
+                        move |ui, state| {
+                            ui.label("Your new name:");
+                            let response = ui.text_edit_singleline(&mut *name.borrow_mut());
+                            if state.just_opened() {
+                                response.request_focus();
+                            }
+                            // "Yes" only with a name
+                            state.set_enabled(0, !name.borrow().trim().is_empty());
+                        }
+                    });
+                    DialogDetails::new(dialog)
+                        .on_reply(move |reply| reply.accepted().then(|| name.take()))
+                        .with_id(RENAME_DIALOG_ID)
+                        .show_if_absent(&mut self.dialogs);
+                }
+
+                // Show wizard dialog leading through pages
+                if ui.button("Wizard").clicked() {
+                    let wizard = WizardDialog::new("Welcome", self.confirmed_name.clone())
+                        .push_page(
+                            WizardPage::new("Name", |ui, name: &mut String| {
+                                ui.label("What's your name?");
+                                ui.text_edit_singleline(name);
+                            })
+                            .validate(|name| !name.trim().is_empty()),
+                        )
+                        .push_page(WizardPage::new("Done", |ui, name: &mut String| {
+                            ui.label(format!("Welcome, {name}!"));
+                        }));
+                    DialogDetails::new(wizard)
+                        .with_id(WIZARD_DIALOG_ID)
+                        .show_if_absent(&mut self.dialogs);
+                }
             });
 
             if !self.confirmed_name.is_empty() {
-                ui.label(format!("Your name is {}", self.confirmed_name));
+                ui.horizontal(|ui| {
+                    ui.label(format!("Your name is {}", self.confirmed_name));
+
+                    // Show confirm popover next to the button
+                    let forget = ui.button("Forget");
+                    if forget.clicked() {
+                        DialogDetails::confirm("Forget name", "Forget your name?")
+                            .anchored_to(&forget)
+                            .with_id(FORGET_NAME_DIALOG_ID)
+                            .show_if_absent(&mut self.dialogs);
+                    }
+                });
             }
         });
     }
@@ -219,6 +289,8 @@ impl Dialog<String> for NameConfirmDialog {
 
         // draw the dialog
         dialog_window(ctx, dctx, "Confirm name").show(ctx, |ui| {
+            // follow the style of the dialog
+            ui.set_style(dctx.style.clone());
             ui.label("Your name: ");
             ui.text_edit_singleline(&mut self.name);
             if ui.button("Done").clicked() {
@@ -0,0 +1,354 @@
+//! Expose dialogs shown with `dialog_window` or `dialog_popover` to assistive technologies through AccessKit.
+//!
+//! Dialogs get the `Dialog` or `AlertDialog` role, labelled by their title.
+//! Modal dialogs hide everything behind them from assistive technologies.
+//!
+//! # Example
+//! ```
+//! use egui::{accesskit::Role, Id};
+//! use egui_dialogs::{DialogDetails, Dialogs};
+//!
+//! let ctx = egui::Context::default();
+//! ctx.enable_accesskit();
+//!
+//! let mut dialogs = Dialogs::new();
+//! DialogDetails::error("Error", "Something went wrong")
+//!     .with_id("error")
+//!     .show(&mut dialogs);
+//!
+//! let output = ctx.run(Default::default(), |ctx| {
+//!     dialogs.show(ctx);
+//! });
+//!
+//! // the node of the dialog is the one egui creates for the window
+//! let update = output.platform_output.accesskit_update.unwrap();
+//! let dialog_id = Id::new("error").with("move").value().into();
+//! let (_, dialog) = update.nodes.iter().find(|(id, _)| *id == dialog_id).unwrap();
+//! assert_eq!(dialog.role(), Role::AlertDialog);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use egui::{accesskit, FullOutput, Id, LayerId, Ui, UiBuilder, ViewportId, WidgetText};
+
+use crate::*;
+
+/// A dialog window or popover shown in the current pass.
+struct AccessibleDialog {
+    layer_id: LayerId,
+    title: String,
+    modal: bool,
+    severity: Option<DialogSeverity>,
+    description: Vec<Id>,
+}
+
+impl AccessibleDialog {
+    /// Update the nodes of the dialog.
+    /// Returns the nodes of the layers not behind the dialog if it's modal.
+    fn apply(self, ctx: &egui::Context) -> Option<HashSet<accesskit::NodeId>> {
+        // the node egui creates for a window or an area
+        let node_id = self.layer_id.id.with("move");
+        let title_id = self.layer_id.id.with("__dialog_title");
+
+        // egui paints the title without a node, so we add one to label the dialog
+        Ui::new(
+            ctx.clone(),
+            title_id,
+            UiBuilder::new()
+                .layer_id(self.layer_id)
+                .accessibility_parent(node_id)
+                .invisible(),
+        );
+        ctx.accesskit_node_builder(title_id, |node| {
+            node.set_role(accesskit::Role::Label);
+            node.set_value(self.title);
+        });
+
+        let is_alert = self.severity.is_some_and(DialogSeverity::is_alert);
+        ctx.accesskit_node_builder(node_id, |node| {
+            if is_alert {
+                node.set_role(accesskit::Role::AlertDialog);
+                // announce the alert as soon as it shows up
+                node.set_live(accesskit::Live::Assertive);
+            } else {
+                node.set_role(accesskit::Role::Dialog);
+            }
+            node.set_labelled_by(vec![title_id.value().into()]);
+            if !self.description.is_empty() {
+                node.set_described_by(
+                    self.description
+                        .iter()
+                        .map(|id| id.value().into())
+                        .collect::<Vec<_>>(),
+                );
+            }
+            if let Some(severity) = self.severity {
+                node.set_class_name(severity.name());
+            }
+            if self.modal {
+                node.set_modal();
+            }
+        });
+
+        // the nodes of the layers are only known once the output is built
+        self.modal.then(|| {
+            ctx.memory(|m| {
+                m.layer_ids()
+                    .filter(|&layer_id| m.is_above_modal_layer(layer_id))
+                    .map(|layer_id| layer_id.id.with("move").value().into())
+                    .collect()
+            })
+        })
+    }
+}
+
+/// Collects the dialogs shown in a pass and updates their nodes at the end of the pass.
+#[derive(Default)]
+struct DialogAccessibility {
+    /// The dialogs of each viewport, including the closed one transitioning out.
+    dialogs: HashMap<ViewportId, Vec<AccessibleDialog>>,
+    /// The nodes of the layers not behind the modal dialog of the pass that just ended.
+    visible: Option<HashSet<accesskit::NodeId>>,
+}
+
+impl egui::Plugin for DialogAccessibility {
+    fn debug_name(&self) -> &'static str {
+        "egui_dialogs::DialogAccessibility"
+    }
+
+    fn on_end_pass(&mut self, ctx: &egui::Context) {
+        self.visible = None;
+        let dialogs = self.dialogs.remove(&ctx.viewport_id()).unwrap_or_default();
+        for dialog in dialogs {
+            if let Some(visible) = dialog.apply(ctx) {
+                self.visible = Some(visible);
+            }
+        }
+    }
+
+    fn output_hook(&mut self, output: &mut FullOutput) {
+        let (Some(visible), Some(update)) = (
+            self.visible.take(),
+            output.platform_output.accesskit_update.as_mut(),
+        ) else {
+            return;
+        };
+
+        // hide everything behind the modal layer, including the mask
+        let root_id = egui::accesskit_root_id().value().into();
+        let Some(children) = update
+            .nodes
+            .iter()
+            .find(|(id, _)| *id == root_id)
+            .map(|(_, root)| root.children().to_vec())
+        else {
+            return;
+        };
+        for (id, node) in &mut update.nodes {
+            if children.contains(id) && !visible.contains(id) {
+                node.set_hidden();
+            }
+        }
+    }
+}
+
+/// Register the dialog window or popover shown in the current pass.
+pub(crate) fn register_dialog(
+    ctx: &egui::Context,
+    layer_id: LayerId,
+    title: &WidgetText,
+    modal: bool,
+) {
+    let is_enabled = ctx
+        .accesskit_node_builder(egui::accesskit_root_id(), |_| ())
+        .is_some();
+    if !is_enabled {
+        return;
+    }
+
+    // only added once
+    ctx.add_plugin(DialogAccessibility::default());
+    ctx.with_plugin(|p: &mut DialogAccessibility| {
+        p.dialogs
+            .entry(ctx.viewport_id())
+            .or_default()
+            .push(AccessibleDialog {
+                layer_id,
+                title: title.text().to_owned(),
+                modal,
+                severity: None,
+                description: vec![],
+            });
+    });
+}
+
+/// Describe the dialog shown with [`dialog_window`] or [`dialog_popover`] in the current pass
+/// for assistive technologies.
+///
+/// The dialog is exposed as an alert dialog if the severity is an alert,
+/// and is described by the widget with the `description` id.
+///
+/// # Example
+/// ```
+/// use egui::{accesskit::Role, Id};
+/// use egui_dialogs::{describe_dialog, dialog_window, Dialog, DialogContext, DialogDetails, DialogSeverity, Dialogs};
+///
+/// struct DeleteDialog;
+///
+/// impl Dialog<()> for DeleteDialog {
+///     fn show(&mut self, ctx: &egui::Context, dctx: &DialogContext) -> Option<()> {
+///         dialog_window(ctx, dctx, "Delete").show(ctx, |ui| {
+///             let label = ui.label("This can't be undone.");
+///             describe_dialog(ctx, Some(DialogSeverity::Warning), label.id);
+///         });
+///         None
+///     }
+/// }
+///
+/// let ctx = egui::Context::default();
+/// ctx.enable_accesskit();
+///
+/// let mut dialogs = Dialogs::new();
+/// DialogDetails::new(DeleteDialog).with_id("delete").show(&mut dialogs);
+///
+/// let output = ctx.run(Default::default(), |ctx| {
+///     dialogs.show(ctx);
+/// });
+///
+/// let update = output.platform_output.accesskit_update.unwrap();
+/// let dialog_id = Id::new("delete").with("move").value().into();
+/// let (_, dialog) = update.nodes.iter().find(|(id, _)| *id == dialog_id).unwrap();
+/// assert_eq!(dialog.role(), Role::AlertDialog);
+/// assert_eq!(dialog.class_name(), Some("warning"));
+/// ```
+pub fn describe_dialog(ctx: &egui::Context, severity: Option<DialogSeverity>, description: Id) {
+    ctx.with_plugin(|p: &mut DialogAccessibility| {
+        let dialogs = p.dialogs.get_mut(&ctx.viewport_id());
+        if let Some(dialog) = dialogs.and_then(|dialogs| dialogs.last_mut()) {
+            dialog.severity = severity;
+            dialog.description.push(description);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{
+        accesskit::{Live, Node, NodeId, Role, TreeUpdate},
+        CentralPanel,
+    };
+
+    use super::*;
+    use crate::harness::Harness;
+
+    fn node(update: &TreeUpdate, id: impl Into<NodeId>) -> &Node {
+        let id = id.into();
+        let (_, node) = update.nodes.iter().find(|(n, _)| *n == id).unwrap();
+        node
+    }
+
+    fn node_of(update: &TreeUpdate, id: Id) -> &Node {
+        node(update, id.value())
+    }
+
+    /// The child of the root containing the node.
+    fn root_child(update: &TreeUpdate, target: Id) -> &Node {
+        fn contains(update: &TreeUpdate, id: NodeId, target: NodeId) -> bool {
+            id == target
+                || node(update, id)
+                    .children()
+                    .iter()
+                    .any(|child| contains(update, *child, target))
+        }
+        let root = node_of(update, egui::accesskit_root_id());
+        let child = root
+            .children()
+            .iter()
+            .find(|child| contains(update, **child, target.value().into()))
+            .unwrap();
+        node(update, *child)
+    }
+
+    /// Run two frames showing a label behind the dialogs,
+    /// and return the tree update and the id of the label.
+    fn run_with_background(harness: &mut Harness) -> (TreeUpdate, Id) {
+        harness.ctx.enable_accesskit();
+        let mut background = Id::NULL;
+        let mut app = |ctx: &egui::Context| {
+            CentralPanel::default().show(ctx, |ui| {
+                background = ui.label("Background").id;
+            });
+        };
+        // the background is inert from the second frame on
+        harness.run_with(&mut app);
+        let (_, output) = harness.run_with(&mut app);
+        (output.platform_output.accesskit_update.unwrap(), background)
+    }
+
+    #[test]
+    fn alerts_are_labelled_and_described() {
+        let mut dialogs = Dialogs::new().animated(false);
+        DialogDetails::error("Error", "Something went wrong")
+            .with_id("error")
+            .show(&mut dialogs);
+        let (update, _) = run_with_background(&mut Harness::new(dialogs));
+
+        let dialog = node_of(&update, Id::new("error").with("move"));
+        assert_eq!(dialog.role(), Role::AlertDialog);
+        assert_eq!(dialog.live(), Some(Live::Assertive));
+        assert_eq!(dialog.class_name(), Some("error"));
+        assert!(dialog.is_modal());
+        assert_eq!(
+            node(&update, dialog.labelled_by()[0]).value(),
+            Some("Error")
+        );
+        assert_eq!(
+            node(&update, dialog.described_by()[0]).value(),
+            Some("Something went wrong")
+        );
+    }
+
+    #[test]
+    fn modal_dialogs_hide_the_background() {
+        let mut dialogs = Dialogs::new().animated(false);
+        DialogDetails::info("Info", "Saved").show(&mut dialogs);
+        let (update, background) = run_with_background(&mut Harness::new(dialogs));
+
+        assert!(root_child(&update, background).is_hidden());
+        let dialog = node_of(&update, Id::new("Info").with("move"));
+        assert!(!dialog.is_hidden());
+    }
+
+    #[test]
+    fn the_background_is_exposed_without_dialogs() {
+        let (update, background) = run_with_background(&mut Harness::new(Dialogs::new()));
+        assert!(!root_child(&update, background).is_hidden());
+    }
+
+    #[test]
+    fn the_next_dialog_is_exposed_while_the_closed_one_fades_out() {
+        let mut dialogs = Dialogs::new();
+        DialogDetails::info("First", "Closed")
+            .with_id("first")
+            .show(&mut dialogs);
+        DialogDetails::info("Second", "Opened")
+            .with_id("second")
+            .show(&mut dialogs);
+        let mut harness = Harness::new(dialogs);
+        harness.ctx.enable_accesskit();
+        harness.run();
+        harness.press(egui::Key::Enter);
+        harness.run();
+        let (_, output) = harness.run_with(|_| {});
+        let update = output.platform_output.accesskit_update.unwrap();
+
+        let second = node_of(&update, Id::new("second").with("move"));
+        assert!(second.is_modal());
+        assert_eq!(
+            node(&update, second.labelled_by()[0]).value(),
+            Some("Second")
+        );
+        let first = node_of(&update, Id::new("first").with("move"));
+        assert_eq!(node(&update, first.labelled_by()[0]).value(), Some("First"));
+    }
+}
@@ -1,9 +1,9 @@
 //! Define the `Dialog` trait which can be implemented to customize dialogs
 //! and `DialogDetails` struct which can be used to show dialogs.
 
-use std::any::Any;
+use std::{any::Any, sync::Arc};
 
-use egui::{Color32, Id, WidgetText};
+use egui::{Color32, Id, Rect, Response, Style, WidgetText};
 
 use crate::*;
 
@@ -34,6 +34,8 @@ use crate::*;
 ///     // draw the dialog
 ///     dialog_window(ctx, dctx, "Confirm name")
 ///       .show(ctx, |ui| {
+///         // follow `Dialogs::style`
+///         ui.set_style(dctx.style.clone());
 ///         ui.label("Your name: ");
 ///         ui.text_edit_singleline(&mut self.name);
 ///         if ui.button("Done").clicked() {
@@ -91,6 +93,19 @@ where
     pub(crate) dialog: Box<dyn Dialog<Reply> + 'a>,
     pub(crate) mask: Option<Color32>,
     pub(crate) id: Option<Id>,
+    pub(crate) transition: Option<DialogTransition>,
+    pub(crate) animation: Option<Option<Easing>>,
+    pub(crate) placement: Option<DialogPlacement>,
+    pub(crate) presentation: DialogPresentation,
+    pub(crate) theme: Option<DialogTheme>,
+    pub(crate) style: Option<Arc<Style>>,
+    pub(crate) movable: bool,
+    pub(crate) resizable: bool,
+    pub(crate) anchor: Option<Rect>,
+    pub(crate) opened: bool,
+    /// The data the dialog was built from, to persist it.
+    #[cfg(feature = "serde")]
+    pub(crate) spec: Option<StandardDialogSpec>,
 }
 
 impl<'a, Reply> DialogDetails<'a, Reply>
@@ -108,6 +123,18 @@ where
             dialog,
             mask: Some(Color32::from_black_alpha(0x80)),
             id: None,
+            transition: None,
+            animation: None,
+            placement: None,
+            presentation: DialogPresentation::Window,
+            theme: None,
+            style: None,
+            movable: false,
+            resizable: false,
+            anchor: None,
+            opened: false,
+            #[cfg(feature = "serde")]
+            spec: None,
         }
     }
 
@@ -144,6 +171,19 @@ where
             }),
             mask: self.mask,
             id: self.id,
+            transition: self.transition,
+            animation: self.animation,
+            placement: self.placement,
+            presentation: self.presentation,
+            theme: self.theme,
+            style: self.style,
+            movable: self.movable,
+            resizable: self.resizable,
+            anchor: self.anchor,
+            opened: self.opened,
+            // the handler can't be persisted
+            #[cfg(feature = "serde")]
+            spec: None,
         }
     }
 
@@ -175,6 +215,195 @@ where
         self.id
     }
 
+    #[inline]
+    /// Set the transition of the dialog, overriding the one of the dialog manager.
+    pub fn with_transition(mut self, transition: DialogTransition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    #[inline]
+    /// Check if a transition is set and return it if there is.
+    pub fn transition(&self) -> Option<DialogTransition> {
+        self.transition
+    }
+
+    #[inline]
+    /// Set the animation function of the dialog, overriding the one of the dialog manager.
+    /// Use None for the dialog to appear and disappear instantly.
+    pub fn with_animation(mut self, animation: Option<Easing>) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
+    #[inline]
+    /// Check if an animation function is set and return it if there is.
+    /// `Some(None)` means the animation of the dialog is disabled.
+    pub fn animation(&self) -> Option<Option<Easing>> {
+        self.animation
+    }
+
+    #[inline]
+    /// Set where the dialog is placed, overriding the placement of the dialog manager.
+    pub fn with_placement(mut self, placement: DialogPlacement) -> Self {
+        self.placement = Some(placement);
+        self
+    }
+
+    #[inline]
+    /// Check if a placement is set and return it if there is.
+    pub fn placement(&self) -> Option<DialogPlacement> {
+        self.placement
+    }
+
+    #[inline]
+    /// Set how the dialog is presented.
+    /// Sheets and drawers are placed by their presentation, can't be moved or resized,
+    /// and slide in unless the dialog has its own transition.
+    pub fn with_presentation(mut self, presentation: DialogPresentation) -> Self {
+        self.presentation = presentation;
+        self
+    }
+
+    #[inline]
+    /// Return how the dialog is presented.
+    pub fn presentation(&self) -> DialogPresentation {
+        self.presentation
+    }
+
+    #[inline]
+    /// Set the theme overriding the one of the dialog manager.
+    pub fn with_theme(mut self, theme: DialogTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    #[inline]
+    /// Check if a theme is set and return it if there is.
+    pub fn theme(&self) -> Option<&DialogTheme> {
+        self.theme.as_ref()
+    }
+
+    #[inline]
+    /// Set the style overriding the one of the dialog manager.
+    /// It only applies to the dialog, not to the rest of the context.
+    ///
+    /// # Example
+    /// ```
+    /// use egui::{Style, Visuals};
+    /// use egui_dialogs::{DialogDetails, Dialogs};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut dialogs = Dialogs::new();
+    ///
+    /// // a dark dialog in a light app
+    /// ctx.set_visuals(Visuals::light());
+    /// let style = Style {
+    ///     visuals: Visuals::dark(),
+    ///     ..Style::default()
+    /// };
+    /// DialogDetails::info("Night mode", "Easy on the eyes")
+    ///     .with_style(style)
+    ///     .show(&mut dialogs);
+    ///
+    /// let _ = ctx.run(Default::default(), |ctx| {
+    ///     dialogs.show(ctx);
+    ///     // the rest of the app keeps its own style
+    ///     assert!(!ctx.style().visuals.dark_mode);
+    /// });
+    /// ```
+    pub fn with_style(mut self, style: impl Into<Arc<Style>>) -> Self {
+        self.style = Some(style.into());
+        self
+    }
+
+    #[inline]
+    /// Check if a style is set and return it if there is.
+    pub fn style(&self) -> Option<&Arc<Style>> {
+        self.style.as_ref()
+    }
+
+    #[inline]
+    /// Set whether the user can move the dialog by its title bar.
+    /// The dialog reopens where the user left it, as long as it has the same id.
+    ///
+    /// # Example
+    /// ```
+    /// use egui_dialogs::{DialogDetails, Dialogs};
+    ///
+    /// let mut dialogs = Dialogs::new();
+    /// DialogDetails::info("Notes", "Drag me")
+    ///     .with_id("notes")
+    ///     .with_movable(true)
+    ///     .show(&mut dialogs);
+    /// ```
+    pub fn with_movable(mut self, movable: bool) -> Self {
+        self.movable = movable;
+        self
+    }
+
+    #[inline]
+    /// Check if the user can move the dialog.
+    pub fn movable(&self) -> bool {
+        self.movable
+    }
+
+    #[inline]
+    /// Set whether the user can resize the dialog.
+    /// The dialog reopens with the size the user left it with, as long as it has the same id.
+    pub fn with_resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    #[inline]
+    /// Check if the user can resize the dialog.
+    pub fn resizable(&self) -> bool {
+        self.resizable
+    }
+
+    #[inline]
+    /// Show the dialog as a popover next to the widget instead of in the middle of the screen.
+    /// The popover has no mask, and clicking outside of it closes it like the cancel key.
+    /// See [`dialog_popover`].
+    ///
+    /// # Example
+    /// ```
+    /// use egui_dialogs::{DialogDetails, Dialogs, StandardReply};
+    ///
+    /// # let ctx = egui::Context::default();
+    /// let mut dialogs = Dialogs::new();
+    /// # let _ = ctx.run(Default::default(), |ctx| {
+    /// # egui::CentralPanel::default().show(ctx, |ui| {
+    /// let trash = ui.button("🗑");
+    /// if trash.clicked() {
+    ///     DialogDetails::confirm("Delete", "Move the file to the trash?")
+    ///         .anchored_to(&trash)
+    ///         .on_accepted(|| println!("Deleted"))
+    ///         .show(&mut dialogs);
+    /// }
+    /// # });
+    /// # });
+    /// ```
+    pub fn anchored_to(self, response: &Response) -> Self {
+        self.anchored_to_rect(response.rect)
+    }
+
+    #[inline]
+    /// Show the dialog as a popover next to the rect.
+    /// See [`Self::anchored_to`].
+    pub fn anchored_to_rect(mut self, rect: Rect) -> Self {
+        self.anchor = Some(rect);
+        self.mask = None;
+        self
+    }
+
+    #[inline]
+    /// Check if the dialog is anchored and return the rect it is anchored to if it is.
+    pub fn anchor(&self) -> Option<Rect> {
+        self.anchor
+    }
+
     #[inline]
     /// Show the dialog.
     pub fn show(self, dialogs: &mut Dialogs<'a>) {
@@ -287,3 +516,46 @@ impl<'a> StandardDialogDetails<'a> {
         self.on_reply(|reply| if reply.accepted() { accepted } else { rejected })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use egui::vec2;
+
+    use super::*;
+    use crate::harness::Harness;
+
+    fn notes<'a>() -> StandardDialogDetails<'a> {
+        DialogDetails::info("Notes", "Drag me")
+            .with_id("notes")
+            .with_movable(true)
+    }
+
+    #[test]
+    fn movable_dialogs_reopen_where_they_were_left() {
+        let mut harness = Harness::new(Dialogs::new().animated(false));
+        notes().show(&mut harness.dialogs);
+        for _ in 0..3 {
+            harness.run();
+        }
+
+        // drag the dialog by its title bar
+        let placed = harness.area_rect("notes");
+        let from = placed.center_top() + vec2(0., 16.);
+        let to = from - vec2(100., 50.);
+        harness.move_pointer(from).button(from, true);
+        harness.run();
+        harness.move_pointer(to);
+        harness.run();
+        harness.button(to, false);
+        harness.run();
+        let moved = harness.area_rect("notes");
+        assert_eq!(moved.min, placed.min - vec2(100., 50.));
+
+        // the dialog reopens where it was left
+        harness.dialogs.pop_front();
+        harness.run();
+        notes().show(&mut harness.dialogs);
+        harness.run();
+        assert_eq!(harness.area_rect("notes"), moved);
+    }
+}
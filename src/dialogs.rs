@@ -1,25 +1,103 @@
 use std::{any::Any, collections::VecDeque, sync::Arc};
 
 use egui::{
-    Color32, CornerRadius, Id, LayerId, Margin, Order, Rect, Sense, Style, Ui, UiBuilder, Vec2,
-    WidgetText,
+    Color32, CornerRadius, Id, Key, KeyboardShortcut, LayerId, Margin, Modifiers, Order, Rect,
+    Sense, Style, Ui, UiBuilder, Vec2, WidgetText,
 };
 
 use crate::*;
 
+/// Keyboard shortcuts used by dialogs.
+/// Each action can be bound to any number of shortcuts.
+///
+/// The shortcuts of the buttons are left to a widget of the dialog
+/// with the keyboard focus, like a text edit, unless it's one of the buttons.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DialogKeys {
+    /// Trigger the default button.
+    pub accept: Vec<KeyboardShortcut>,
+
+    /// Trigger the cancel button, or close the dialog if there is none.
+    pub cancel: Vec<KeyboardShortcut>,
+
+    /// Move focus to the next button.
+    pub next: Vec<KeyboardShortcut>,
+
+    /// Move focus to the previous button.
+    pub previous: Vec<KeyboardShortcut>,
+}
+
+impl DialogKeys {
+    /// Key bindings with no shortcut at all.
+    pub fn none() -> Self {
+        Self {
+            accept: vec![],
+            cancel: vec![],
+            next: vec![],
+            previous: vec![],
+        }
+    }
+
+    /// Consume the first of the shortcuts pressed in this frame.
+    /// Returns whether one was pressed.
+    pub fn consume(ctx: &egui::Context, shortcuts: &[KeyboardShortcut]) -> bool {
+        ctx.input_mut(|i| shortcuts.iter().any(|s| i.consume_shortcut(s)))
+    }
+
+    /// Swap the left and right arrow keys in all shortcuts,
+    /// so arrows follow the buttons of a right-to-left dialog.
+    pub fn mirrored(mut self) -> Self {
+        let mirror = |shortcuts: &mut Vec<KeyboardShortcut>| {
+            for shortcut in shortcuts {
+                shortcut.logical_key = match shortcut.logical_key {
+                    Key::ArrowLeft => Key::ArrowRight,
+                    Key::ArrowRight => Key::ArrowLeft,
+                    key => key,
+                };
+            }
+        };
+        mirror(&mut self.accept);
+        mirror(&mut self.cancel);
+        mirror(&mut self.next);
+        mirror(&mut self.previous);
+        self
+    }
+}
+
+impl Default for DialogKeys {
+    fn default() -> Self {
+        Self {
+            accept: vec![KeyboardShortcut::new(Modifiers::NONE, Key::Enter)],
+            cancel: vec![KeyboardShortcut::new(Modifiers::NONE, Key::Escape)],
+            // tab goes through all widgets, moved by egui
+            next: vec![KeyboardShortcut::new(Modifiers::NONE, Key::ArrowRight)],
+            previous: vec![KeyboardShortcut::new(Modifiers::NONE, Key::ArrowLeft)],
+        }
+    }
+}
+
 /// Information about the current dialog update.
+#[derive(Clone)]
 pub struct DialogContext {
     /// The updated dialog id if there is one.
     pub dialog_id: Option<Id>,
 
     /// The current animation function.
     /// If None, the dialog will not be animated.
-    pub animation: Option<fn(f32) -> f32>,
+    pub animation: Option<Easing>,
 
-    /// The current opacity of the dialog.
-    /// Used for animation.
+    /// The opacity the transition gives the dialog at the current progress.
     pub opacity: f32,
 
+    /// The eased progress of the transition,
+    /// from 0 when the dialog is hidden to 1 when it is fully shown.
+    /// Goes back to 0 when the dialog is closed.
+    pub progress: f32,
+
+    /// The transition of the dialog.
+    /// Windows created with [`dialog_window`] follow it automatically.
+    pub transition: DialogTransition,
+
     /// Whether the dialog has been closed.
     /// If animation is enabled, this will be true if the dialog is fading out.
     pub already_closed: bool,
@@ -32,6 +110,49 @@ pub struct DialogContext {
 
     /// The maximum size of the dialog.
     pub max_size: Option<Vec2>,
+
+    /// The keyboard shortcuts of the dialog.
+    pub keys: DialogKeys,
+
+    /// Whether this is the first time the dialog is shown.
+    /// Use it to place the initial keyboard focus.
+    pub just_opened: bool,
+
+    /// Whether the dialog is shown over a mask.
+    /// Modal dialogs keep the keyboard focus within their own layer.
+    pub modal: bool,
+
+    /// The direction in which the dialog is laid out.
+    pub direction: LayoutDirection,
+
+    /// How the dialog is presented.
+    /// Windows created with [`dialog_window`] follow it automatically.
+    pub presentation: DialogPresentation,
+
+    /// The look of the dialog.
+    /// Windows created with [`dialog_window`] follow it automatically.
+    pub theme: DialogTheme,
+
+    /// The style of the dialog, which may differ from the style of the context.
+    /// Windows created with [`dialog_window`] take their frame and title from it,
+    /// and custom dialogs set it on their contents with `ui.set_style(dctx.style.clone())`.
+    pub style: Arc<Style>,
+
+    /// Where the dialog is placed.
+    /// Windows created with [`dialog_window`] follow it automatically.
+    pub placement: DialogPlacement,
+
+    /// Whether the user can move the dialog.
+    /// Windows created with [`dialog_window`] reopen where the user left them.
+    pub movable: bool,
+
+    /// Whether the user can resize the dialog.
+    pub resizable: bool,
+
+    /// The rect the dialog is anchored to if it is shown as a popover.
+    /// Popovers are placed next to it instead of following the placement.
+    /// Dialogs shown with [`dialog_popover`] are placed next to it.
+    pub anchor: Option<Rect>,
 }
 
 /// The response of a dialog.
@@ -102,6 +223,58 @@ pub trait AbstractDialog {
 
     /// Return the dialog's id if there is one.
     fn id(&self) -> Option<Id>;
+
+    /// Return the transition overriding the one of the dialog manager if there is one.
+    fn transition(&self) -> Option<DialogTransition> {
+        None
+    }
+
+    /// Return the animation function overriding the one of the dialog manager if there is one.
+    /// `Some(None)` disables the animation of the dialog.
+    fn animation(&self) -> Option<Option<Easing>> {
+        None
+    }
+
+    /// Return the placement overriding the one of the dialog manager if there is one.
+    fn placement(&self) -> Option<DialogPlacement> {
+        None
+    }
+
+    /// Return how the dialog is presented.
+    fn presentation(&self) -> DialogPresentation {
+        DialogPresentation::Window
+    }
+
+    /// Return the theme overriding the one of the dialog manager if there is one.
+    fn theme(&self) -> Option<&DialogTheme> {
+        None
+    }
+
+    /// Return the style overriding the one of the dialog manager if there is one.
+    fn style(&self) -> Option<Arc<Style>> {
+        None
+    }
+
+    /// Return whether the user can move the dialog.
+    fn movable(&self) -> bool {
+        false
+    }
+
+    /// Return whether the user can resize the dialog.
+    fn resizable(&self) -> bool {
+        false
+    }
+
+    /// Return the rect the dialog is anchored to if it is shown as a popover.
+    fn anchor(&self) -> Option<Rect> {
+        None
+    }
+
+    /// Return the data describing the dialog if it can be persisted.
+    #[cfg(feature = "serde")]
+    fn spec(&self) -> Option<&StandardDialogSpec> {
+        None
+    }
 }
 
 impl<'a, R> AbstractDialog for DialogDetails<'a, R>
@@ -109,9 +282,18 @@ where
     R: Any,
 {
     fn update(&mut self, ctx: &egui::Context, dctx: &DialogContext) -> Option<Box<dyn Any>> {
-        self.dialog
-            .show(ctx, dctx)
-            .map(|r| Box::new(r) as Box<dyn Any>)
+        let reply = if self.opened {
+            self.dialog.show(ctx, dctx)
+        } else {
+            self.opened = true;
+            let dctx = &DialogContext {
+                just_opened: true,
+                ..dctx.clone()
+            };
+            self.dialog.show(ctx, dctx)
+        };
+
+        reply.map(|r| Box::new(r) as Box<dyn Any>)
     }
 
     fn mask(&self) -> Option<Color32> {
@@ -121,6 +303,53 @@ where
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn transition(&self) -> Option<DialogTransition> {
+        self.transition
+    }
+
+    fn animation(&self) -> Option<Option<Easing>> {
+        self.animation
+    }
+
+    fn placement(&self) -> Option<DialogPlacement> {
+        self.placement
+    }
+
+    fn presentation(&self) -> DialogPresentation {
+        self.presentation
+    }
+
+    fn theme(&self) -> Option<&DialogTheme> {
+        self.theme.as_ref()
+    }
+
+    fn style(&self) -> Option<Arc<Style>> {
+        self.style.clone()
+    }
+
+    fn movable(&self) -> bool {
+        self.movable
+    }
+
+    fn resizable(&self) -> bool {
+        self.resizable
+    }
+
+    fn anchor(&self) -> Option<Rect> {
+        self.anchor
+    }
+
+    #[cfg(feature = "serde")]
+    fn spec(&self) -> Option<&StandardDialogSpec> {
+        // only dialogs still identified by the id of their spec can be restored
+        self.spec.as_ref().filter(|spec| {
+            spec.id
+                .as_deref()
+                .map(Id::new)
+                .is_some_and(|id| self.id == Some(id))
+        })
+    }
 }
 
 /// A dialog manager for showing dialogs on an egui::Context.
@@ -170,10 +399,51 @@ pub struct Dialogs<'a> {
 
     /// The animation function.
     /// Set to None to disable animation.
-    pub animation: Option<fn(f32) -> f32>,
-
+    /// Can be overridden for each dialog with [`DialogDetails::with_animation`],
+    /// and is disabled for all dialogs with [`set_reduced_motion`].
+    pub animation: Option<Easing>,
+
+    /// The transition of the dialogs.
+    /// Can be overridden for each dialog with [`DialogDetails::with_transition`].
+    /// Sheets and drawers slide in with its duration.
+    pub transition: DialogTransition,
+
+    /// Where the dialogs are placed.
+    /// Can be overridden for each dialog with [`DialogDetails::with_placement`].
+    pub placement: DialogPlacement,
+
+    /// The width of the screen below which dialogs are shown
+    /// with [`DialogPresentation::FullScreen`], whatever their own presentation.
+    /// Set to 0 to never show dialogs full screen.
+    pub full_screen_width: f32,
+
+    /// The look of the dialogs.
+    /// If None, it follows the style of the dialogs.
+    /// Can be overridden for each dialog with [`DialogDetails::with_theme`].
+    pub theme: Option<DialogTheme>,
+
+    /// The closed dialog transitioning out.
     fading_dialog: Option<Box<dyn AbstractDialog + 'a>>,
 
+    /// The layers drawn by the fading dialog in the last frame.
+    fading_layers: Vec<LayerId>,
+
+    /// The linear progress of the transition of the current dialog.
+    progress: f32,
+
+    /// The linear progress of the transition of the fading dialog.
+    fading_progress: f32,
+
+    /// The mask color shown between dialogs.
+    mask_transition: MaskTransition,
+
+    /// The widget focused before the dialogs were shown.
+    /// `Some` as long as there are dialogs to show.
+    focus_to_restore: Option<Option<Id>>,
+
+    /// The widget to give the focus back to in the next frame.
+    focus_to_restore_next_frame: Option<Id>,
+
     /// Override the style of the dialogs.
     pub style: Option<Arc<Style>>,
 
@@ -182,6 +452,13 @@ pub struct Dialogs<'a> {
 
     /// The maximum size of a dialog.
     pub max_size: Option<Vec2>,
+
+    /// The keyboard shortcuts of the dialogs.
+    pub keys: DialogKeys,
+
+    /// The direction in which the dialogs are laid out.
+    /// If None, it follows the locale of the built-in texts.
+    pub direction: Option<LayoutDirection>,
 }
 
 impl Dialogs<'_> {
@@ -192,10 +469,22 @@ impl Dialogs<'_> {
             mask_margin: Margin::ZERO,
             mask_rounding: CornerRadius::ZERO,
             animation: Some(egui::emath::easing::cubic_out),
+            transition: DialogTransition::default(),
+            placement: DialogPlacement::default(),
+            full_screen_width: 480.,
+            theme: None,
             fading_dialog: None,
+            fading_layers: vec![],
+            progress: 0.,
+            fading_progress: 0.,
+            mask_transition: MaskTransition::HIDDEN,
+            focus_to_restore: None,
+            focus_to_restore_next_frame: None,
             style: None,
             min_size: None,
             max_size: None,
+            keys: DialogKeys::default(),
+            direction: None,
         }
     }
 
@@ -214,7 +503,7 @@ impl Dialogs<'_> {
     }
 
     /// Set the animation function. Use None to disable animation.
-    pub fn animate(mut self, animation: Option<fn(f32) -> f32>) -> Self {
+    pub fn animate(mut self, animation: Option<Easing>) -> Self {
         self.animation = animation;
         if animation.is_none() && self.fading_dialog.is_some() {
             self.fading_dialog = None;
@@ -235,6 +524,35 @@ impl Dialogs<'_> {
         self
     }
 
+    #[inline]
+    /// Set the transition of the dialogs.
+    pub fn transition(mut self, transition: DialogTransition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    #[inline]
+    /// Set where the dialogs are placed.
+    pub fn placement(mut self, placement: DialogPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    #[inline]
+    /// Set the width of the screen below which dialogs are shown full screen.
+    /// Use 0 to never show dialogs full screen.
+    pub fn full_screen_width(mut self, width: f32) -> Self {
+        self.full_screen_width = width;
+        self
+    }
+
+    #[inline]
+    /// Set the look of the dialogs.
+    pub fn theme(mut self, theme: DialogTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
     #[inline]
     /// Override the style of the dialogs.
     pub fn style(mut self, style: impl Into<Arc<Style>>) -> Self {
@@ -255,6 +573,22 @@ impl Dialogs<'_> {
         self.max_size = Some(size.into());
         self
     }
+
+    #[inline]
+    /// Set the keyboard shortcuts of the dialogs.
+    /// Use `DialogKeys::none()` to disable keyboard handling.
+    pub fn keys(mut self, keys: DialogKeys) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    #[inline]
+    /// Set the direction in which the dialogs are laid out.
+    /// Use None to follow the locale of the built-in texts.
+    pub fn direction(mut self, direction: Option<LayoutDirection>) -> Self {
+        self.direction = direction;
+        self
+    }
 }
 
 impl Default for Dialogs<'_> {
@@ -281,13 +615,14 @@ impl<'a> Dialogs<'a> {
     #[inline]
     /// Show a dialog if it is not already open.
     pub fn add_if_absent<Reply: 'a + Any>(&mut self, dialog: DialogDetails<'a, Reply>) {
-        if dialog.id.map_or(true, |id| !self.is_open(id)) {
+        if dialog.id.is_none_or(|id| !self.is_open(id)) {
             self.add(dialog);
         }
     }
 
     /// Get the currently open dialog.
     #[inline]
+    #[allow(clippy::borrowed_box)]
     pub fn current_dialog(&self) -> Option<&Box<dyn AbstractDialog + 'a>> {
         self.dialogs.front()
     }
@@ -307,6 +642,7 @@ impl<'a> Dialogs<'a> {
 
     /// Get the last dialog.
     #[inline]
+    #[allow(clippy::borrowed_box)]
     pub fn last_dialog(&self) -> Option<&Box<dyn AbstractDialog + 'a>> {
         self.dialogs.back()
     }
@@ -336,31 +672,96 @@ impl<'a> Dialogs<'a> {
     }
 }
 
-impl Dialogs<'_> {
+impl<'a> Dialogs<'a> {
     const ID_NAME: &'static str = "dialog_mask";
 
     /// Paint a mask with the given color.
     /// This will intercept all user interactions with background.
     /// Returns the painted opacity.
     pub fn show_mask(&self, ctx: &egui::Context, color: Color32, dialog_on: bool) -> f32 {
+        let how_on =
+            self.transition_progress(ctx, dialog_on, self.animation_of(None), &self.transition);
+        if how_on > 0. {
+            self.paint_mask(ctx, color, how_on);
+        }
+        how_on
+    }
+
+    /// The animation of the dialog, or of the dialogs if there is none.
+    /// None if motion is reduced.
+    #[allow(clippy::borrowed_box)]
+    fn animation_of(&self, dialog: Option<&Box<dyn AbstractDialog + '_>>) -> Option<Easing> {
+        if reduced_motion() {
+            return None;
+        }
+        dialog
+            .and_then(|dialog| dialog.animation())
+            .unwrap_or(self.animation)
+    }
+
+    /// The presentation of the dialog, full screen if the screen is too narrow.
+    #[allow(clippy::borrowed_box)]
+    fn presentation_of(
+        &self,
+        ctx: &egui::Context,
+        dialog: &Box<dyn AbstractDialog + '_>,
+    ) -> DialogPresentation {
+        if ctx.content_rect().width() < self.full_screen_width {
+            DialogPresentation::FullScreen
+        } else {
+            dialog.presentation()
+        }
+    }
+
+    /// The transition of the dialog, or of the dialog manager if there is no dialog.
+    #[allow(clippy::borrowed_box)]
+    fn transition_of(
+        &self,
+        ctx: &egui::Context,
+        dialog: Option<&Box<dyn AbstractDialog + '_>>,
+    ) -> DialogTransition {
+        dialog
+            .and_then(|dialog| {
+                dialog.transition().or_else(|| {
+                    // the presentation slides the dialog in the time of the dialog manager
+                    let mut transition = self.presentation_of(ctx, dialog).transition()?;
+                    transition.duration = self.transition.duration;
+                    Some(transition)
+                })
+            })
+            .unwrap_or(self.transition)
+    }
+
+    /// Advance the transition of the dialogs and return its eased progress.
+    fn transition_progress(
+        &self,
+        ctx: &egui::Context,
+        dialog_on: bool,
+        animation: Option<Easing>,
+        transition: &DialogTransition,
+    ) -> f32 {
         let id = Id::new((ctx.viewport_id(), Self::ID_NAME));
 
-        let how_on = match self.animation {
-            Some(easing) => {
-                let value = ctx.animate_bool_with_easing(id, dialog_on, easing);
-                if value == 0. {
-                    return 0.;
-                }
-                value
-            }
+        match animation {
+            Some(easing) => ctx.animate_bool_with_time_and_easing(
+                id,
+                dialog_on,
+                transition.duration_or_default(ctx),
+                easing,
+            ),
             None => {
                 if dialog_on {
                     1.
                 } else {
-                    return 0.;
+                    0.
                 }
             }
-        };
+        }
+    }
+
+    /// Paint the mask with the opacity.
+    fn paint_mask(&self, ctx: &egui::Context, color: Color32, how_on: f32) {
+        let id = Id::new((ctx.viewport_id(), Self::ID_NAME));
 
         let layer_id = LayerId {
             order: egui::Order::Background,
@@ -368,11 +769,11 @@ impl Dialogs<'_> {
         };
 
         let mask_rect = ctx.content_rect() - self.mask_margin;
-        let mut mask_ui = Ui::new(
-            ctx.clone(),
-            id,
-            UiBuilder::new().layer_id(layer_id).max_rect(mask_rect),
-        );
+        let mut builder = UiBuilder::new().layer_id(layer_id).max_rect(mask_rect);
+        if let Some(style) = &self.style {
+            builder = builder.style(Arc::clone(style));
+        }
+        let mut mask_ui = Ui::new(ctx.clone(), id, builder);
 
         mask_ui.set_opacity(how_on);
 
@@ -393,103 +794,265 @@ impl Dialogs<'_> {
                 focused.surrender_focus();
             }
         }
-
-        how_on
     }
 
     /// Show the currently open dialog if there is one.
     /// Returns None if there is no dialog to show.
     /// Returns Some(DialogResponse) with no reply if a dialog is open.
     /// Returns Some(DialogResponse) with reply if a dialog is closed.
+    ///
+    /// When a dialog is closed, the next one in the queue cross-fades in over it
+    /// while the mask stays up, blending into the mask color of the next dialog.
     pub fn show(&mut self, ctx: &egui::Context) -> Option<DialogResponse> {
         // is a dialog open?
-        let on = !self.dialogs.is_empty() && self.fading_dialog.is_none();
-        // how opaque is the mask?
-        let how_on = if on || self.fading_dialog.is_some() {
-            // get the mask color from the dialog which to be shown
-            let mask_color = match &self.fading_dialog {
-                Some(fading_dialog) => fading_dialog.mask(),
-                None => self.dialogs.front().unwrap().mask(), // self.dialogs mustn't be empty here
-            };
-            if let Some(mask_color) = mask_color {
-                // paint mask
-                self.show_mask(ctx, mask_color, on)
-            } else if let Some(animation) = self.animation {
-                // no mask to paint, but we give time for dialog to animate
-                ctx.animate_bool_with_easing(
-                    Id::new((ctx.viewport_id(), Self::ID_NAME)),
-                    on,
-                    animation,
-                )
-            } else {
-                // a dialog without animation is still visible
-                1.
+        let on = !self.dialogs.is_empty();
+        // the background accepts the focus again after a frame without modal dialogs
+        if let Some(focused) = self.focus_to_restore_next_frame.take() {
+            ctx.memory_mut(|m| m.request_focus(focused));
+        }
+        // the mask follows the current dialog, and fades out with the last closed one
+        let top_dialog = self.dialogs.front().or(self.fading_dialog.as_ref());
+        let mask_color = self.dialogs.front().and_then(|dialog| dialog.mask());
+
+        // remember the focus to give it back when all dialogs are closed,
+        // only taken away by modal dialogs: popovers leave it where the user clicks
+        if on && mask_color.is_some() && self.focus_to_restore.is_none() {
+            self.focus_to_restore = Some(ctx.memory(|m| m.focused()));
+        }
+        let mask_animation = self.animation_of(top_dialog);
+        let mask_duration = self.transition_of(ctx, top_dialog).duration_or_default(ctx);
+        let shown_mask_color = self.mask_transition.advance(
+            ctx,
+            mask_color.unwrap_or(Color32::TRANSPARENT),
+            mask_animation,
+            mask_duration,
+        );
+        if shown_mask_color != Color32::TRANSPARENT {
+            self.paint_mask(ctx, shown_mask_color, 1.);
+        }
+
+        // the closed dialog transitions out under the next one
+        let mut fading_dialog = self.fading_dialog.take();
+        if let Some(dialog) = &fading_dialog {
+            let animation = self.animation_of(Some(dialog));
+            self.fading_progress =
+                self.advance_progress(ctx, self.fading_progress, false, animation, dialog);
+            if self.fading_progress <= 0. {
+                fading_dialog = None;
             }
-        } else {
-            // nothing to show
-            0.
-        };
+        }
 
-        // nothing to show anymore
-        if how_on == 0. {
-            if self.fading_dialog.is_some() {
-                // the dialog has just faded out
-                self.fading_dialog = None;
-                // we need a repaint to show the next dialog if there is one
-                if !self.dialogs.is_empty() {
-                    ctx.request_repaint();
-                }
+        let Some(mut dialog) = self.dialogs.pop_front() else {
+            if let Some(fading_dialog) = fading_dialog {
+                let fading_id = fading_dialog.id();
+                self.update_fading_dialog(ctx, fading_dialog);
+                return Some(DialogResponse {
+                    id: fading_id,
+                    reply: None,
+                });
+            }
+            // all dialogs are closed, restore the focus
+            if let Some(Some(focused)) = self.focus_to_restore.take() {
+                self.focus_to_restore_next_frame = Some(focused);
+                ctx.request_repaint();
             }
             return None;
+        };
+
+        let animation = self.animation_of(Some(&dialog));
+        self.progress = self.advance_progress(ctx, self.progress, true, animation, &dialog);
+        let modal = mask_color.is_some();
+        let (reply, layers) = self.update_dialog(ctx, &mut dialog, self.progress, false, modal);
+
+        // dialogs without an id share the window of the same title,
+        // so the cross-fade is skipped when they follow each other
+        if let Some(fading_dialog) = fading_dialog {
+            if !layers
+                .iter()
+                .any(|layer| self.fading_layers.contains(layer))
+            {
+                self.update_fading_dialog(ctx, fading_dialog);
+            }
         }
 
-        // the dialog to show and whether it was already closed
-        let (dialog, already_closed) = match self.fading_dialog {
-            Some(ref mut fading_dialog) => (Some(fading_dialog), true),
-            None => (self.dialogs.front_mut(), false),
+        let mut response = DialogResponse {
+            id: dialog.id(),
+            reply: None,
         };
+        if let Some(reply) = reply {
+            response.reply = Some(reply);
+            // the dialog transitions out with its own animation
+            if animation.is_some() {
+                self.fading_dialog = Some(dialog);
+                self.fading_layers = layers;
+                self.fading_progress = self.progress;
+            }
+            // the next dialog transitions in from the start
+            self.progress = 0.;
+            ctx.request_repaint();
+        } else {
+            self.dialogs.push_front(dialog);
+        }
 
-        if let Some(dialog) = dialog {
-            let id = dialog.id();
+        Some(response)
+    }
 
-            let mut response = DialogResponse { id, reply: None };
+    /// Update the closed dialog transitioning out, ignoring its reply.
+    fn update_fading_dialog(
+        &mut self,
+        ctx: &egui::Context,
+        mut dialog: Box<dyn AbstractDialog + 'a>,
+    ) {
+        let (_, layers) = self.update_dialog(ctx, &mut dialog, self.fading_progress, true, false);
+        self.fading_dialog = Some(dialog);
+        self.fading_layers = layers;
+    }
 
-            let outer_style = if let Some(ref style) = self.style {
-                let outer_style = ctx.style();
-                ctx.set_style(Arc::clone(style));
-                Some(outer_style)
-            } else {
-                None
-            };
+    /// Advance the linear progress of the transition of a dialog.
+    #[allow(clippy::borrowed_box)]
+    fn advance_progress(
+        &self,
+        ctx: &egui::Context,
+        progress: f32,
+        dialog_on: bool,
+        animation: Option<Easing>,
+        dialog: &Box<dyn AbstractDialog + '_>,
+    ) -> f32 {
+        let target = if dialog_on { 1. } else { 0. };
+        if animation.is_none() {
+            return target;
+        }
 
-            let dctx = &DialogContext {
-                dialog_id: id,
-                animation: self.animation,
-                opacity: how_on,
-                already_closed,
-                mask_rect: ctx.content_rect() - self.mask_margin,
-                min_size: self.min_size,
-                max_size: self.max_size,
-            };
-            if let Some(reply) = dialog.update(ctx, dctx) {
-                // if the dialog is already closed, we ignore the reply
-                if !already_closed {
-                    response.reply = Some(reply);
-                    let closed_dialog = self.dialogs.pop_front();
-                    if self.animation.is_some() {
-                        self.fading_dialog = closed_dialog;
-                    }
-                }
-            }
+        let duration = self
+            .transition_of(ctx, Some(dialog))
+            .duration_or_default(ctx);
+        let step = ctx.input(|i| i.stable_dt) / duration;
+        let progress = if dialog_on {
+            (progress + step).min(target)
+        } else {
+            (progress - step).max(target)
+        };
+        if progress != target {
+            ctx.request_repaint();
+        }
+        progress
+    }
 
-            if let Some(outer_style) = outer_style {
-                ctx.set_style(outer_style);
-            }
+    /// Update a dialog at the linear progress of its transition
+    /// and return its reply if there is one, and the layers it has drawn.
+    fn update_dialog(
+        &self,
+        ctx: &egui::Context,
+        dialog: &mut Box<dyn AbstractDialog + '_>,
+        progress: f32,
+        already_closed: bool,
+        modal: bool,
+    ) -> (Option<Box<dyn Any>>, Vec<LayerId>) {
+        let animation = self.animation_of(Some(dialog));
+        let transition = self.transition_of(ctx, Some(dialog));
+        let how_on = animation.map_or(progress, |easing| easing(progress));
+
+        let direction = self
+            .direction
+            .unwrap_or_else(LayoutDirection::of_active_locale);
+        let keys = match direction {
+            LayoutDirection::LeftToRight => self.keys.clone(),
+            LayoutDirection::RightToLeft => self.keys.clone().mirrored(),
+        };
+
+        let mask_rect = ctx.content_rect() - self.mask_margin;
+        let presentation = self.presentation_of(ctx, dialog);
+        // scoped to the dialog, the style of the context is left untouched
+        let style = dialog
+            .style()
+            .or_else(|| self.style.clone())
+            .unwrap_or_else(|| ctx.style());
+        let dctx = &DialogContext {
+            dialog_id: dialog.id(),
+            animation,
+            opacity: transition.transform(how_on, mask_rect, mask_rect).opacity,
+            progress: how_on,
+            transition,
+            already_closed,
+            mask_rect,
+            min_size: self.min_size,
+            max_size: self.max_size,
+            keys,
+            just_opened: false,
+            modal,
+            direction,
+            presentation,
+            theme: dialog
+                .theme()
+                .or(self.theme.as_ref())
+                .cloned()
+                .unwrap_or_else(|| DialogTheme::from_style(&style)),
+            style,
+            placement: presentation
+                .placement()
+                .or(dialog.placement())
+                .unwrap_or(self.placement),
+            // sheets and drawers stay attached to their edge
+            movable: dialog.movable() && presentation == DialogPresentation::Window,
+            resizable: dialog.resizable() && presentation == DialogPresentation::Window,
+            // popovers take the whole screen too when it is too narrow
+            anchor: dialog
+                .anchor()
+                .filter(|_| presentation != DialogPresentation::FullScreen),
+        };
+        // forget the layers of dialogs that weren't updated by us
+        take_transition_layers(ctx);
+        let reply = dialog.update(ctx, dctx);
+        let layers = take_transition_layers(ctx);
+        apply_transition(ctx, &layers, &transition, how_on, mask_rect);
 
-            Some(response)
+        (reply, layers)
+    }
+}
+
+/// The mask color blending from the mask of a dialog into the mask of the next one.
+#[derive(Clone, Copy)]
+struct MaskTransition {
+    from: Color32,
+    to: Color32,
+    progress: f32,
+}
+
+impl MaskTransition {
+    const HIDDEN: Self = Self {
+        from: Color32::TRANSPARENT,
+        to: Color32::TRANSPARENT,
+        progress: 1.,
+    };
+
+    /// The color at the progress.
+    fn color(&self, animation: Option<Easing>) -> Color32 {
+        let progress = animation.map_or(1., |easing| easing(self.progress));
+        self.from.lerp_to_gamma(self.to, progress)
+    }
+
+    /// Advance the transition to the target color and return the color to show.
+    fn advance(
+        &mut self,
+        ctx: &egui::Context,
+        target: Color32,
+        animation: Option<Easing>,
+        duration: f32,
+    ) -> Color32 {
+        if target != self.to {
+            // blend from the color shown now
+            self.from = self.color(animation);
+            self.to = target;
+            self.progress = 0.;
+        }
+
+        if animation.is_some() && self.progress < 1. {
+            self.progress = (self.progress + ctx.input(|i| i.stable_dt) / duration).min(1.);
+            ctx.request_repaint();
         } else {
-            None
+            self.progress = 1.;
         }
+        self.color(animation)
     }
 }
 
@@ -529,3 +1092,96 @@ impl<'a> Dialogs<'a> {
         self.add(StandardDialogDetails::error(title, message));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use egui::Key;
+
+    use super::*;
+    use crate::harness::Harness;
+
+    #[test]
+    fn presentations_keep_the_duration_of_the_dialogs() {
+        let mut dialogs = Dialogs::new().transition(DialogTransition::fade().duration(0.5));
+        DialogDetails::info("Sheet", "Slides in")
+            .with_presentation(DialogPresentation::BottomSheet)
+            .show(&mut dialogs);
+        let harness = Harness::new(dialogs);
+
+        let transition = harness
+            .dialogs
+            .transition_of(&harness.ctx, harness.dialogs.dialogs.front());
+        assert_eq!(transition.duration, Some(0.5));
+        let rect = Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(200., 100.));
+        assert_eq!(
+            transition.transform(0.5, rect, rect),
+            DialogTransition::slide_from_bottom().transform(0.5, rect, rect)
+        );
+    }
+
+    #[test]
+    fn clicking_out_of_a_popover_keeps_the_focus_there() {
+        let mut harness = Harness::new(Dialogs::new().animated(false));
+
+        // the texts of the fields, their rects and their ids
+        let fields = std::cell::RefCell::new(vec![(String::new(), Rect::NOTHING, Id::NULL); 2]);
+        let mut app = |ctx: &egui::Context| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                for (text, rect, id) in fields.borrow_mut().iter_mut() {
+                    let response = ui.text_edit_singleline(text);
+                    (*rect, *id) = (response.rect, response.id);
+                }
+            });
+        };
+        harness.run_with(&mut app);
+        let first = fields.borrow()[0].2;
+        harness.ctx.memory_mut(|m| m.request_focus(first));
+        harness.run_with(&mut app);
+
+        let anchor = Rect::from_min_size(egui::pos2(400., 100.), egui::vec2(24., 24.));
+        DialogDetails::confirm("Delete", "Move the file to the trash?")
+            .anchored_to_rect(anchor)
+            .show(&mut harness.dialogs);
+        harness.run_with(&mut app);
+        harness.run_with(&mut app);
+
+        // the click into the second field cancels the popover
+        let (_, rect, second) = fields.borrow()[1].clone();
+        harness.click(rect.center());
+        let (response, _) = harness.run_with(&mut app);
+        assert_eq!(
+            response.and_then(|response| response.reply().ok()),
+            Some(StandardReply::No)
+        );
+        for _ in 0..3 {
+            harness.run_with(&mut app);
+        }
+        assert_eq!(harness.focused(), Some(second));
+    }
+
+    /// Reply to the first of two queued errors and show the next one.
+    fn close_first_error(second_title: &str) -> Harness<'static> {
+        let mut dialogs = Dialogs::new();
+        dialogs.error("Error", "The file is missing");
+        dialogs.error(second_title.to_owned(), "The disk is full");
+        let mut harness = Harness::new(dialogs);
+        harness.run();
+        harness.press(Key::Enter);
+        assert!(harness.run().is_some_and(|response| response.is_reply()));
+        harness.run();
+        harness
+    }
+
+    #[test]
+    fn closed_dialogs_cross_fade_into_the_next_one() {
+        let harness = close_first_error("Another error");
+        assert!(harness.dialogs.fading_dialog.is_some());
+    }
+
+    #[test]
+    fn dialogs_sharing_a_window_do_not_cross_fade() {
+        let harness = close_first_error("Error");
+        assert!(harness.dialogs.fading_dialog.is_none());
+        assert_eq!(harness.dialogs.count(), 1);
+    }
+}
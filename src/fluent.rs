@@ -0,0 +1,190 @@
+//! Translate the built-in texts and your own with [Fluent](https://projectfluent.org) resources.
+//!
+//! # Example
+//! ```
+//! use std::sync::Arc;
+//!
+//! use egui_dialogs::{set_locale, set_translator, FluentArgs, FluentTranslator, StandardReply};
+//!
+//! let translator = FluentTranslator::new()
+//!     .with_resource(
+//!         "en-US",
+//!         "delete-files = { $count ->
+//!             [one] Delete {$count} file?
+//!            *[other] Delete {$count} files?
+//!         }",
+//!     )
+//!     .unwrap()
+//!     .with_resource("fr", "ok = Valider")
+//!     .unwrap();
+//! let translator = Arc::new(translator);
+//! set_translator(Some(translator.clone()));
+//! set_locale(Some("en-US".into()));
+//!
+//! let mut args = FluentArgs::new();
+//! args.set("count", 1);
+//! assert_eq!(translator.format("delete-files", Some(&args)).unwrap(), "Delete 1 file?");
+//! args.set("count", 3);
+//! assert_eq!(translator.format("delete-files", Some(&args)).unwrap(), "Delete 3 files?");
+//!
+//! // the built-in texts are loaded as well, and can be overridden
+//! assert_eq!(StandardReply::Cancel.localize(), "Cancel");
+//! set_locale(Some("fr-FR".into()));
+//! assert_eq!(StandardReply::Ok.localize(), "Valider");
+//! assert_eq!(StandardReply::Cancel.localize(), "Annuler");
+//! // missing messages fall back to the fallback locale
+//! assert_eq!(translator.format("delete-files", Some(&args)).unwrap(), "Delete 3 files?");
+//! # set_translator(None);
+//! # set_locale(None);
+//! ```
+
+use std::{collections::BTreeMap, fmt};
+
+use fluent_bundle::{concurrent::FluentBundle, FluentError, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+pub use fluent_bundle::{FluentArgs, FluentValue};
+
+use crate::*;
+
+/// An error adding a Fluent resource to a [`FluentTranslator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FluentResourceError {
+    /// The locale is not a valid language identifier.
+    InvalidLocale(String),
+    /// The resource has syntax errors.
+    Syntax(Vec<FluentError>),
+}
+
+impl fmt::Display for FluentResourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLocale(locale) => write!(f, "invalid locale: {locale}"),
+            Self::Syntax(errors) => {
+                write!(f, "invalid Fluent resource")?;
+                for error in errors {
+                    write!(f, "\n{error}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for FluentResourceError {}
+
+/// A [`Translator`] backed by Fluent bundles, one for each locale.
+///
+/// Starts with the built-in texts, keyed like [`StandardReply::key`].
+/// Resources added later override messages with the same id,
+/// so the texts of your own dialogs and the standard buttons
+/// go through the same translations.
+///
+/// The locale is negotiated from [`preferred_locales`] whenever a text is formatted.
+/// Messages missing in that locale are taken from [`FALLBACK_LOCALE`].
+pub struct FluentTranslator {
+    bundles: BTreeMap<String, FluentBundle<FluentResource>>,
+}
+
+impl Default for FluentTranslator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for FluentTranslator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FluentTranslator")
+            .field("locales", &self.locales())
+            .finish()
+    }
+}
+
+impl FluentTranslator {
+    /// Create a translator with the built-in texts.
+    pub fn new() -> Self {
+        let mut sources = BTreeMap::<_, String>::new();
+        for (key, locale, text) in standard_texts() {
+            let source = sources.entry(locale).or_default();
+            source.push_str(&format!("{key} = {text}\n"));
+        }
+
+        let mut translator = Self::empty();
+        for (locale, source) in sources {
+            translator
+                .add_resource(locale, &source)
+                .expect("the built-in texts should be valid Fluent resources");
+        }
+        translator
+    }
+
+    /// Create a translator without any texts.
+    pub fn empty() -> Self {
+        Self {
+            bundles: BTreeMap::new(),
+        }
+    }
+
+    /// Add a Fluent resource for the locale.
+    /// Its messages override those with the same id added before.
+    pub fn add_resource(&mut self, locale: &str, source: &str) -> Result<(), FluentResourceError> {
+        let resource = FluentResource::try_new(source.to_owned()).map_err(|(_, errors)| {
+            FluentResourceError::Syntax(errors.into_iter().map(FluentError::ParserError).collect())
+        })?;
+
+        let bundle = match self.bundles.get_mut(locale) {
+            Some(bundle) => bundle,
+            None => {
+                let langid: LanguageIdentifier = locale
+                    .parse()
+                    .map_err(|_| FluentResourceError::InvalidLocale(locale.to_owned()))?;
+                let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+                // egui can't render the bidi isolation marks around placeables
+                bundle.set_use_isolating(false);
+                self.bundles.entry(locale.to_owned()).or_insert(bundle)
+            }
+        };
+        bundle.add_resource_overriding(resource);
+        Ok(())
+    }
+
+    /// Add a Fluent resource for the locale.
+    /// Its messages override those with the same id added before.
+    pub fn with_resource(
+        mut self,
+        locale: &str,
+        source: &str,
+    ) -> Result<Self, FluentResourceError> {
+        self.add_resource(locale, source)?;
+        Ok(self)
+    }
+
+    /// The locales with resources.
+    pub fn locales(&self) -> Vec<&str> {
+        self.bundles.keys().map(String::as_str).collect()
+    }
+
+    /// Format the message with the id in the preferred locale.
+    /// Returns None if no locale has the message.
+    pub fn format(&self, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+        let locales = self.locales();
+        let locale = negotiate_locale(preferred_locales(), &locales, FALLBACK_LOCALE);
+
+        [locale, FALLBACK_LOCALE].into_iter().find_map(|locale| {
+            let bundle = self.bundles.get(locale)?;
+            let pattern = bundle.get_message(id)?.value()?;
+            let mut errors = vec![];
+            Some(
+                bundle
+                    .format_pattern(pattern, args, &mut errors)
+                    .into_owned(),
+            )
+        })
+    }
+}
+
+impl Translator for FluentTranslator {
+    fn translate(&self, key: &str) -> Option<String> {
+        self.format(key, None)
+    }
+}
@@ -0,0 +1,619 @@
+//! Dialogs asking for several values at once, laid out as a form.
+
+use std::{marker::PhantomData, ops::RangeInclusive};
+
+use egui::{
+    emath::Numeric, Color32, ComboBox, DragValue, Grid, Response, TextEdit, Ui, WidgetText,
+};
+
+use crate::*;
+
+/// A value entered in a [`FormDialog`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormValue {
+    Text(String),
+    Number(f64),
+    Bool(bool),
+    /// The option chosen.
+    Choice(String),
+}
+
+impl From<&str> for FormValue {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+impl From<String> for FormValue {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<f64> for FormValue {
+    fn from(number: f64) -> Self {
+        Self::Number(number)
+    }
+}
+
+impl From<i64> for FormValue {
+    fn from(number: i64) -> Self {
+        Self::Number(number as f64)
+    }
+}
+
+impl From<bool> for FormValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+/// The values entered in a [`FormDialog`], by key, in the order of the fields.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FormValues {
+    values: Vec<(String, FormValue)>,
+}
+
+impl FormValues {
+    /// The value of the field with the key.
+    pub fn get(&self, key: &str) -> Option<&FormValue> {
+        self.values.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// The text of a text field.
+    pub fn text(&self, key: &str) -> Option<&str> {
+        match self.get(key)? {
+            FormValue::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// The number of a number field.
+    pub fn number(&self, key: &str) -> Option<f64> {
+        match self.get(key)? {
+            FormValue::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    /// The number of a number field, rounded to an integer.
+    pub fn integer(&self, key: &str) -> Option<i64> {
+        self.number(key).map(|number| number.round() as i64)
+    }
+
+    /// Whether a checkbox is checked.
+    pub fn bool(&self, key: &str) -> Option<bool> {
+        match self.get(key)? {
+            FormValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The option chosen in a choice field.
+    pub fn choice(&self, key: &str) -> Option<&str> {
+        match self.get(key)? {
+            FormValue::Choice(choice) => Some(choice),
+            _ => None,
+        }
+    }
+
+    /// The keys and values of the fields.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FormValue)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), v))
+    }
+}
+
+/// A reply built from the values of a [`FormDialog`],
+/// like your own struct.
+///
+/// # Example
+/// ```
+/// use egui_dialogs::{FormDialog, FormValues, FromForm};
+///
+/// struct Connection {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// impl FromForm for Connection {
+///     fn from_form(values: &FormValues) -> Result<Self, String> {
+///         let host = values.text("host").unwrap_or_default().trim();
+///         if host.contains(' ') {
+///             return Err("The host can't contain spaces".into());
+///         }
+///         Ok(Self {
+///             host: host.to_owned(),
+///             port: values.integer("port").unwrap_or(22) as u16,
+///         })
+///     }
+/// }
+///
+/// let form = FormDialog::new("New connection")
+///     .text("host")
+///     .required()
+///     .number("port", 1..=65535)
+///     .initial(22)
+///     .reply_as::<Connection>();
+/// ```
+pub trait FromForm: Sized {
+    /// Build the reply from the values, or tell what is wrong with them.
+    /// The error is shown below the form, and the dialog can't be accepted until it's fixed.
+    fn from_form(values: &FormValues) -> Result<Self, String>;
+}
+
+impl FromForm for FormValues {
+    fn from_form(values: &FormValues) -> Result<Self, String> {
+        Ok(values.clone())
+    }
+}
+
+/// A function checking the value of a field, returning the error to show if it's invalid.
+pub type FormValidation<'a> = dyn Fn(&FormValue) -> Result<(), String> + 'a;
+
+enum FieldKind {
+    Text(String),
+    Number {
+        value: f64,
+        range: RangeInclusive<f64>,
+        integral: bool,
+    },
+    Checkbox(bool),
+    Choice {
+        options: Vec<String>,
+        selected: usize,
+    },
+}
+
+struct FormField<'a> {
+    key: String,
+    label: WidgetText,
+    kind: FieldKind,
+    required: bool,
+    validation: Option<Box<FormValidation<'a>>>,
+    // errors are shown once the user has changed the field
+    touched: bool,
+}
+
+impl FormField<'_> {
+    fn value(&self) -> FormValue {
+        match &self.kind {
+            FieldKind::Text(text) => FormValue::Text(text.clone()),
+            FieldKind::Number { value, .. } => FormValue::Number(*value),
+            FieldKind::Checkbox(value) => FormValue::Bool(*value),
+            FieldKind::Choice { options, selected } => {
+                FormValue::Choice(options.get(*selected).cloned().unwrap_or_default())
+            }
+        }
+    }
+
+    /// The error of the field if it's invalid.
+    fn error(&self) -> Option<String> {
+        if self.required && matches!(&self.kind, FieldKind::Text(text) if text.trim().is_empty()) {
+            return Some(translate("required"));
+        }
+        let validation = self.validation.as_ref()?;
+        validation(&self.value()).err()
+    }
+
+    /// Show the widget editing the field.
+    fn show(&mut self, ui: &mut Ui) -> Response {
+        let id_salt = &self.key;
+        match &mut self.kind {
+            FieldKind::Text(text) => ui.add(TextEdit::singleline(text)),
+            FieldKind::Number {
+                value,
+                range,
+                integral,
+            } => {
+                let mut drag = DragValue::new(value).range(range.clone());
+                if *integral {
+                    drag = drag.fixed_decimals(0);
+                }
+                ui.add(drag)
+            }
+            FieldKind::Checkbox(value) => ui.checkbox(value, ""),
+            FieldKind::Choice { options, selected } => {
+                let text = options.get(*selected).cloned().unwrap_or_default();
+                let inner = ComboBox::from_id_salt(id_salt)
+                    .selected_text(text)
+                    .show_ui(ui, |ui| {
+                        let mut changed = false;
+                        for (index, option) in options.iter().enumerate() {
+                            changed |= ui.selectable_value(selected, index, option).changed();
+                        }
+                        changed
+                    });
+                let mut response = inner.response;
+                if inner.inner == Some(true) {
+                    response.mark_changed();
+                }
+                response
+            }
+        }
+    }
+}
+
+/// A dialog asking for several values at once,
+/// with labelled fields laid out in a grid.
+///
+/// Fields are added with [`FormDialog::text`], [`FormDialog::number`],
+/// [`FormDialog::checkbox`] and [`FormDialog::choice`],
+/// and methods like [`FormDialog::label`] or [`FormDialog::required`] apply to the last one.
+///
+/// Built on [`StandardDialog`], with its title, OK and Cancel buttons and keys.
+/// The first field has the focus when the form opens, and Tab moves to the next one.
+/// OK is disabled while a field is invalid, and the errors of the fields
+/// are shown below them once they have been changed.
+/// Replies with the values, or the reply built with [`FormDialog::reply_as`],
+/// and with None when cancelled.
+///
+/// # Example
+/// ```
+/// use egui_dialogs::{DialogDetails, FormDialog};
+///
+/// let form = FormDialog::new("New connection")
+///     .text("host")
+///     .label("Host")
+///     .required()
+///     .number("port", 1..=65535)
+///     .label("Port")
+///     .initial(22)
+///     .checkbox("tls")
+///     .label("Use TLS")
+///     .choice("auth", ["Password", "Key", "None"])
+///     .label("Authentication");
+///
+/// # let mut dialogs = egui_dialogs::Dialogs::new();
+/// DialogDetails::new(form)
+///     .on_reply(|values| {
+///         if let Some(values) = values {
+///             let host = values.text("host").unwrap();
+///             let port = values.integer("port").unwrap();
+///             println!("connecting to {host}:{port}");
+///         }
+///     })
+///     .show(&mut dialogs);
+/// ```
+pub struct FormDialog<'a, Reply = FormValues> {
+    /// The standard dialog showing the form, to set its severity or image.
+    /// OK is its first button.
+    pub dialog: StandardDialog<'a, bool>,
+    fields: Vec<FormField<'a>>,
+    reply: PhantomData<Reply>,
+}
+
+impl<'a> FormDialog<'a, FormValues> {
+    pub fn new(title: impl Into<WidgetText>) -> Self {
+        let dialog = StandardDialog::new(title, "")
+            .buttons(vec![
+                (StandardReply::Ok.localize().into(), true),
+                (StandardReply::Cancel.localize().into(), false),
+            ])
+            .cancel_button(Some(1));
+        Self {
+            dialog,
+            fields: vec![],
+            reply: PhantomData,
+        }
+    }
+}
+
+impl<'a, Reply> FormDialog<'a, Reply>
+where
+    Reply: FromForm,
+{
+    /// Reply with your own type built from the values
+    pub fn reply_as<R: FromForm>(self) -> FormDialog<'a, R> {
+        FormDialog {
+            dialog: self.dialog,
+            fields: self.fields,
+            reply: PhantomData,
+        }
+    }
+
+    /// Set the text shown above the fields
+    #[inline]
+    pub fn content(mut self, content: impl Into<WidgetText>) -> Self {
+        self.dialog.content = content.into();
+        self
+    }
+
+    /// Add a single line text field
+    pub fn text(self, key: impl Into<String>) -> Self {
+        self.field(key, FieldKind::Text(String::new()))
+    }
+
+    /// Add a number field, kept within the range.
+    /// Integer ranges only allow integers.
+    /// Inverted bounds are swapped, and a NaN bound leaves that side unbounded.
+    pub fn number<N: Numeric>(self, key: impl Into<String>, range: RangeInclusive<N>) -> Self {
+        let bound = |bound: f64, unbounded: f64| if bound.is_nan() { unbounded } else { bound };
+        let start = bound(range.start().to_f64(), f64::NEG_INFINITY);
+        let end = bound(range.end().to_f64(), f64::INFINITY);
+        let range = start.min(end)..=start.max(end);
+        self.field(
+            key,
+            FieldKind::Number {
+                value: 0f64.max(*range.start()).min(*range.end()),
+                range,
+                integral: N::INTEGRAL,
+            },
+        )
+    }
+
+    /// Add a checkbox
+    pub fn checkbox(self, key: impl Into<String>) -> Self {
+        self.field(key, FieldKind::Checkbox(false))
+    }
+
+    /// Add a field to choose one of the options, the first one by default
+    pub fn choice(
+        self,
+        key: impl Into<String>,
+        options: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        let options = options.into_iter().map(Into::into).collect();
+        self.field(
+            key,
+            FieldKind::Choice {
+                options,
+                selected: 0,
+            },
+        )
+    }
+
+    fn field(mut self, key: impl Into<String>, kind: FieldKind) -> Self {
+        let key = key.into();
+        self.fields.push(FormField {
+            label: key.clone().into(),
+            key,
+            kind,
+            required: false,
+            validation: None,
+            touched: false,
+        });
+        self
+    }
+
+    /// Set the label of the last field, its key by default
+    #[inline]
+    pub fn label(mut self, label: impl Into<WidgetText>) -> Self {
+        if let Some(field) = self.fields.last_mut() {
+            field.label = label.into();
+        }
+        self
+    }
+
+    /// Set the initial value of the last field.
+    /// Choices take the text of one of their options, numbers are kept within the range.
+    pub fn initial(mut self, value: impl Into<FormValue>) -> Self {
+        let Some(field) = self.fields.last_mut() else {
+            return self;
+        };
+        match (&mut field.kind, value.into()) {
+            (FieldKind::Text(text), FormValue::Text(value)) => *text = value,
+            // NaN isn't a number the field can show
+            (FieldKind::Number { value, range, .. }, FormValue::Number(number))
+                if !number.is_nan() =>
+            {
+                *value = number.max(*range.start()).min(*range.end());
+            }
+            (FieldKind::Checkbox(checked), FormValue::Bool(value)) => *checked = value,
+            (
+                FieldKind::Choice { options, selected },
+                FormValue::Text(choice) | FormValue::Choice(choice),
+            ) => {
+                if let Some(index) = options.iter().position(|option| *option == choice) {
+                    *selected = index;
+                }
+            }
+            // the value doesn't fit the field
+            _ => {}
+        }
+        self
+    }
+
+    /// Require the last field to be filled in, if it's a text field
+    #[inline]
+    pub fn required(mut self) -> Self {
+        if let Some(field) = self.fields.last_mut() {
+            field.required = true;
+        }
+        self
+    }
+
+    /// Check the value of the last field, returning the error to show if it's invalid
+    #[inline]
+    pub fn validate(mut self, validation: impl Fn(&FormValue) -> Result<(), String> + 'a) -> Self {
+        if let Some(field) = self.fields.last_mut() {
+            field.validation = Some(Box::new(validation));
+        }
+        self
+    }
+
+    /// The values of the fields.
+    pub fn values(&self) -> FormValues {
+        values_of(&self.fields)
+    }
+}
+
+fn values_of(fields: &[FormField<'_>]) -> FormValues {
+    FormValues {
+        values: fields
+            .iter()
+            .map(|field| (field.key.clone(), field.value()))
+            .collect(),
+    }
+}
+
+/// Show the fields in a grid with their errors below them,
+/// giving the focus to the first one if `focus_first` is set.
+/// Returns whether all fields are valid.
+fn show_fields(
+    ui: &mut Ui,
+    fields: &mut [FormField<'_>],
+    is_rtl: bool,
+    error_color: Color32,
+    focus_first: bool,
+) -> bool {
+    let mut is_valid = true;
+    Grid::new("__form_fields")
+        .num_columns(2)
+        .spacing(ui.spacing().item_spacing * 2.)
+        .show(ui, |ui| {
+            for (index, field) in fields.iter_mut().enumerate() {
+                // the labels are at the start of the rows
+                let response = if is_rtl {
+                    let response = field.show(ui);
+                    ui.label(field.label.clone());
+                    response
+                } else {
+                    ui.label(field.label.clone());
+                    field.show(ui)
+                };
+                field.touched |= response.changed();
+                if focus_first && index == 0 {
+                    response.request_focus();
+                }
+                ui.end_row();
+
+                let error = field.error();
+                is_valid &= error.is_none();
+                if let Some(error) = error.filter(|_| field.touched) {
+                    let error = egui::RichText::new(error).color(error_color).small();
+                    if is_rtl {
+                        ui.label(error);
+                        ui.label("");
+                    } else {
+                        ui.label("");
+                        ui.label(error);
+                    }
+                    ui.end_row();
+                }
+            }
+        });
+    is_valid
+}
+
+impl<'a, Reply> Dialog<Option<Reply>> for FormDialog<'a, Reply>
+where
+    Reply: FromForm,
+{
+    fn show(&mut self, ctx: &egui::Context, dctx: &DialogContext) -> Option<Option<Reply>> {
+        let Self { dialog, fields, .. } = self;
+        let mut reply = None;
+        let is_rtl = dctx.direction.is_rtl();
+        let error_color = dctx.theme.accent(DialogSeverity::Error);
+        let has_content = !dialog.content.is_empty();
+        let content = dialog.content.clone();
+
+        let accepted = dialog.show_with_body(
+            ctx,
+            dctx,
+            Some(&mut |ui: &mut Ui, state: &mut DialogBodyState| {
+                if has_content {
+                    ui.label(content.clone());
+                    ui.add_space(ui.spacing().item_spacing.y);
+                }
+
+                let mut result = None;
+                if show_fields(ui, fields, is_rtl, error_color, state.just_opened()) {
+                    match Reply::from_form(&values_of(fields)) {
+                        Ok(value) => result = Some(value),
+                        Err(error) => {
+                            ui.label(egui::RichText::new(error).color(error_color));
+                        }
+                    }
+                }
+                // OK only with a reply
+                state.set_enabled(0, result.is_some());
+                reply = result;
+            }),
+        )?;
+
+        Some(if accepted { reply } else { None })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::Key;
+
+    use super::*;
+    use crate::harness::Harness;
+
+    /// Open the form in a harness without animations.
+    fn open(form: FormDialog<'static>) -> Harness<'static> {
+        let mut dialogs = Dialogs::new();
+        dialogs.animation = None;
+        DialogDetails::new(form).show(&mut dialogs);
+        let mut harness = Harness::new(dialogs);
+        harness.run();
+        harness
+    }
+
+    fn reply(harness: &mut Harness) -> Option<Option<FormValues>> {
+        harness.run().and_then(|response| response.reply().ok())
+    }
+
+    #[test]
+    fn forms_are_filled_from_the_keyboard() {
+        let form = FormDialog::new("Sign in")
+            .text("user")
+            .required()
+            .text("password");
+        let mut harness = open(form);
+
+        // the first field has the focus
+        harness.type_text("ada");
+        harness.run();
+        harness.press(Key::Tab);
+        harness.run();
+        harness.type_text("secret");
+        harness.run();
+        // enter leaves the text edit and accepts the form
+        harness.press(Key::Enter);
+        let values = reply(&mut harness).flatten().unwrap();
+        assert_eq!(values.text("user"), Some("ada"));
+        assert_eq!(values.text("password"), Some("secret"));
+    }
+
+    #[test]
+    fn escape_closes_an_open_combo_box_first() {
+        let form = FormDialog::new("Theme").choice("theme", ["Light", "Dark"]);
+        let mut harness = open(form);
+
+        // the combo box has the focus and opens with the space key
+        harness.press(Key::Space);
+        harness.run();
+        assert!(egui::Popup::is_any_open(&harness.ctx));
+
+        harness.press(Key::Escape);
+        assert_eq!(reply(&mut harness).map(|values| values.is_none()), None);
+        assert!(!egui::Popup::is_any_open(&harness.ctx));
+
+        harness.press(Key::Escape);
+        assert!(reply(&mut harness).is_some_and(|values| values.is_none()));
+    }
+
+    #[test]
+    fn odd_number_ranges_dont_panic() {
+        let form = FormDialog::new("Server")
+            .number("port", RangeInclusive::new(10, 1))
+            .number("ratio", f64::NAN..=1.)
+            .initial(f64::NAN)
+            .number("scale", 2.0..=f64::NAN);
+        let values: Vec<_> = form.fields.iter().map(FormField::value).collect();
+        assert_eq!(
+            values,
+            [
+                FormValue::Number(1.),
+                FormValue::Number(0.),
+                FormValue::Number(2.),
+            ]
+        );
+
+        // and they can be shown
+        open(form);
+    }
+}
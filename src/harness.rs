@@ -0,0 +1,122 @@
+//! A headless context running dialogs frame by frame, for the tests.
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use egui::{Event, FullOutput, Id, Key, Modifiers, PointerButton, Pos2, RawInput, Rect};
+
+use crate::*;
+
+// the tests changing global settings like the locale run one at a time
+static GLOBALS: Mutex<()> = Mutex::new(());
+
+/// Runs dialogs in a headless context with the events queued since the last frame.
+/// Global settings are reset when it's dropped.
+pub(crate) struct Harness<'a> {
+    pub ctx: egui::Context,
+    pub dialogs: Dialogs<'a>,
+    events: Vec<Event>,
+    modifiers: Modifiers,
+    _globals: MutexGuard<'static, ()>,
+}
+
+impl<'a> Harness<'a> {
+    pub fn new(dialogs: Dialogs<'a>) -> Self {
+        Self {
+            ctx: egui::Context::default(),
+            dialogs,
+            events: vec![],
+            modifiers: Modifiers::NONE,
+            _globals: GLOBALS.lock().unwrap_or_else(PoisonError::into_inner),
+        }
+    }
+
+    /// Run a frame and return the response of the dialogs.
+    pub fn run(&mut self) -> Option<DialogResponse> {
+        self.run_with(|_| {}).0
+    }
+
+    /// Run a frame, showing the app after the dialogs.
+    pub fn run_with(
+        &mut self,
+        mut app: impl FnMut(&egui::Context),
+    ) -> (Option<DialogResponse>, FullOutput) {
+        let input = RawInput {
+            events: std::mem::take(&mut self.events),
+            modifiers: std::mem::take(&mut self.modifiers),
+            ..Default::default()
+        };
+        let mut response = None;
+        let output = self.ctx.run(input, |ctx| {
+            response = self.dialogs.show(ctx);
+            app(ctx);
+        });
+        (response, output)
+    }
+
+    /// Press and release a key in the next frame.
+    pub fn press(&mut self, key: Key) -> &mut Self {
+        self.press_with(Modifiers::NONE, key)
+    }
+
+    /// Press and release a key with modifiers in the next frame.
+    pub fn press_with(&mut self, modifiers: Modifiers, key: Key) -> &mut Self {
+        self.modifiers = modifiers;
+        for pressed in [true, false] {
+            self.events.push(Event::Key {
+                key,
+                physical_key: None,
+                pressed,
+                repeat: false,
+                modifiers,
+            });
+        }
+        self
+    }
+
+    /// Type the text in the next frame.
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        self.events.push(Event::Text(text.to_owned()));
+        self
+    }
+
+    /// Move the pointer in the next frame.
+    pub fn move_pointer(&mut self, pos: Pos2) -> &mut Self {
+        self.events.push(Event::PointerMoved(pos));
+        self
+    }
+
+    /// Press or release the primary button where the pointer is in the next frame.
+    pub fn button(&mut self, pos: Pos2, pressed: bool) -> &mut Self {
+        self.events.push(Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        });
+        self
+    }
+
+    /// Click at the position in the next frame.
+    pub fn click(&mut self, pos: Pos2) -> &mut Self {
+        self.move_pointer(pos).button(pos, true).button(pos, false)
+    }
+
+    /// The rect of the area or window with the id in the last frame.
+    pub fn area_rect(&self, id: impl Into<Id>) -> Rect {
+        let id = id.into();
+        self.ctx.memory(|m| m.area_rect(id)).unwrap()
+    }
+
+    /// The widget with the keyboard focus.
+    pub fn focused(&self) -> Option<Id> {
+        self.ctx.memory(|m| m.focused())
+    }
+}
+
+impl Drop for Harness<'_> {
+    fn drop(&mut self) {
+        set_reduced_motion(false);
+        set_locale(None);
+        set_translator(None);
+    }
+}
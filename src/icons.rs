@@ -0,0 +1,256 @@
+//! The icons of standard dialogs, as images or painted with vector shapes.
+
+use std::{f32::consts::PI, sync::Arc};
+
+use egui::{
+    emath::NumExt, include_image, pos2, vec2, Color32, Image, ImageSource, Painter, Pos2, Rect,
+    Response, Sense, Shape, Stroke, Ui, Vec2,
+};
+
+use crate::*;
+
+/// The built-in icons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StandardIcon {
+    Info,
+    Success,
+    Confirm,
+    Warning,
+    Error,
+    Close,
+}
+
+impl StandardIcon {
+    /// The icon of a severity.
+    pub fn of(severity: DialogSeverity) -> Self {
+        match severity {
+            DialogSeverity::Info => Self::Info,
+            DialogSeverity::Success => Self::Success,
+            DialogSeverity::Confirm => Self::Confirm,
+            DialogSeverity::Warning => Self::Warning,
+            DialogSeverity::Error => Self::Error,
+        }
+    }
+
+    /// The SVG image of the icon.
+    /// It can only be shown with an image loader supporting SVG, like the ones of `egui_extras`.
+    pub fn image(self) -> ImageSource<'static> {
+        match self {
+            Self::Info => include_image!("assets/info.svg"),
+            Self::Success => include_image!("assets/success.svg"),
+            Self::Confirm => include_image!("assets/confirm.svg"),
+            Self::Warning => include_image!("assets/warning.svg"),
+            Self::Error => include_image!("assets/error.svg"),
+            Self::Close => include_image!("assets/close.svg"),
+        }
+    }
+
+    /// Paint the icon in the rect with vector shapes, without any image loader.
+    /// The severity icons are filled with the color, the close icon is stroked with it.
+    pub fn paint(self, painter: &Painter, rect: Rect, color: Color32) {
+        let size = rect.width().min(rect.height());
+        let center = rect.center();
+        let radius = size / 2.;
+        let stroke = Stroke::new((size * 0.09).at_least(1.), Color32::WHITE);
+        // from the center, in units of the radius
+        let at = |x: f32, y: f32| center + vec2(x, y) * radius;
+
+        match self {
+            Self::Info => {
+                painter.circle_filled(center, radius, color);
+                painter.circle_filled(at(0., -0.38), stroke.width * 0.7, stroke.color);
+                painter.line_segment([at(0., -0.12), at(0., 0.45)], stroke);
+            }
+            Self::Success => {
+                painter.circle_filled(center, radius, color);
+                painter.add(Shape::line(
+                    vec![at(-0.42, 0.02), at(-0.12, 0.32), at(0.45, -0.3)],
+                    stroke,
+                ));
+            }
+            Self::Confirm => {
+                painter.circle_filled(center, radius, color);
+                // the hook of the question mark, from its left end over the top
+                let hook_center = at(0., -0.2);
+                let mut points: Vec<Pos2> = (0..=12)
+                    .map(|i| {
+                        let angle = PI + i as f32 / 12. * (PI + 0.6);
+                        hook_center + 0.25 * radius * Vec2::angled(angle)
+                    })
+                    .collect();
+                points.push(at(0., 0.15));
+                points.push(at(0., 0.22));
+                painter.add(Shape::line(points, stroke));
+                painter.circle_filled(at(0., 0.45), stroke.width * 0.7, stroke.color);
+            }
+            Self::Warning => {
+                let top = pos2(center.x, center.y - radius * 0.92);
+                let left = pos2(center.x - radius, center.y + radius * 0.8);
+                let right = pos2(center.x + radius, center.y + radius * 0.8);
+                painter.add(Shape::convex_polygon(
+                    vec![top, right, left],
+                    color,
+                    Stroke::NONE,
+                ));
+                painter.line_segment([at(0., -0.4), at(0., 0.25)], stroke);
+                painter.circle_filled(at(0., 0.52), stroke.width * 0.7, stroke.color);
+            }
+            Self::Error => {
+                painter.circle_filled(center, radius, color);
+                painter.line_segment([at(-0.32, -0.32), at(0.32, 0.32)], stroke);
+                painter.line_segment([at(0.32, -0.32), at(-0.32, 0.32)], stroke);
+            }
+            Self::Close => {
+                let stroke = Stroke::new((size * 0.1).at_least(1.), color);
+                let rect = Rect::from_center_size(center, Vec2::splat(size));
+                painter.line_segment([rect.left_top(), rect.right_bottom()], stroke);
+                painter.line_segment([rect.right_top(), rect.left_bottom()], stroke);
+            }
+        }
+    }
+}
+
+/// How the built-in icons are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IconRendering {
+    /// As images if an image loader supports SVG, otherwise painted.
+    #[default]
+    Auto,
+
+    /// As SVG images, which need an image loader supporting SVG.
+    Images,
+
+    /// Painted with vector shapes.
+    Painted,
+}
+
+/// A function painting an icon in the rect with the accent color.
+pub type PaintIcon = dyn Fn(&Painter, Rect, Color32) + Send + Sync;
+
+/// A custom icon.
+#[derive(Clone)]
+pub enum DialogIcon {
+    /// An image shown by the image loaders of the context.
+    Image(ImageSource<'static>),
+
+    /// A function painting the icon in the rect with the accent color.
+    Painted(Arc<PaintIcon>),
+}
+
+impl DialogIcon {
+    /// A function painting the icon in the rect with the accent color.
+    pub fn painted(paint: impl Fn(&Painter, Rect, Color32) + Send + Sync + 'static) -> Self {
+        Self::Painted(Arc::new(paint))
+    }
+}
+
+impl std::fmt::Debug for DialogIcon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Image(image) => f.debug_tuple("Image").field(image).finish(),
+            Self::Painted(_) => f.write_str("Painted"),
+        }
+    }
+}
+
+impl PartialEq for DialogIcon {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Image(a), Self::Image(b)) => a.uri() == b.uri(),
+            (Self::Painted(a), Self::Painted(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// The icons of standard dialogs for each severity.
+///
+/// # Example
+/// ```
+/// use egui_dialogs::{DialogDetails, DialogIcon, DialogTheme, Dialogs, IconRendering};
+///
+/// let mut theme = DialogTheme::default();
+/// // never rely on an image loader
+/// theme.icons.rendering = IconRendering::Painted;
+/// // a square for errors
+/// theme.icons.error = Some(DialogIcon::painted(|painter, rect, color| {
+///     painter.rect_filled(rect.shrink(4.), 0, color);
+/// }));
+///
+/// let mut dialogs = Dialogs::new().theme(theme);
+/// DialogDetails::error("Error", "Something went wrong").show(&mut dialogs);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DialogIcons {
+    /// How the built-in icons are drawn.
+    pub rendering: IconRendering,
+    /// Custom icons replacing the built-in ones.
+    pub info: Option<DialogIcon>,
+    pub success: Option<DialogIcon>,
+    pub confirm: Option<DialogIcon>,
+    pub warning: Option<DialogIcon>,
+    pub error: Option<DialogIcon>,
+}
+
+impl DialogIcons {
+    /// The custom icon of a severity if there is one.
+    pub fn get(&self, severity: DialogSeverity) -> Option<&DialogIcon> {
+        match severity {
+            DialogSeverity::Info => self.info.as_ref(),
+            DialogSeverity::Success => self.success.as_ref(),
+            DialogSeverity::Confirm => self.confirm.as_ref(),
+            DialogSeverity::Warning => self.warning.as_ref(),
+            DialogSeverity::Error => self.error.as_ref(),
+        }
+    }
+
+    /// Set the custom icon of a severity.
+    pub fn set(&mut self, severity: DialogSeverity, icon: DialogIcon) {
+        let slot = match severity {
+            DialogSeverity::Info => &mut self.info,
+            DialogSeverity::Success => &mut self.success,
+            DialogSeverity::Confirm => &mut self.confirm,
+            DialogSeverity::Warning => &mut self.warning,
+            DialogSeverity::Error => &mut self.error,
+        };
+        *slot = Some(icon);
+    }
+
+    /// Show the icon of a severity at the size, painted with the color if it is painted.
+    pub fn show(
+        &self,
+        ui: &mut Ui,
+        severity: DialogSeverity,
+        size: f32,
+        color: Color32,
+    ) -> Response {
+        let size = Vec2::splat(size);
+        let image = match self.get(severity) {
+            Some(DialogIcon::Image(image)) => image.clone(),
+            Some(DialogIcon::Painted(paint)) => {
+                let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+                paint(ui.painter(), rect, color);
+                return response;
+            }
+            None => {
+                let icon = StandardIcon::of(severity);
+                let image = icon.image();
+                let is_painted = match self.rendering {
+                    IconRendering::Images => false,
+                    IconRendering::Painted => true,
+                    // no image loader supports SVG
+                    IconRendering::Auto => Image::new(image.clone())
+                        .load_for_size(ui.ctx(), size)
+                        .is_err(),
+                };
+                if is_painted {
+                    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+                    icon.paint(ui.painter(), rect, color);
+                    return response;
+                }
+                image
+            }
+        };
+        ui.add(Image::new(image).fit_to_exact_size(size))
+    }
+}
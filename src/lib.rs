@@ -267,6 +267,8 @@
 //!     // draw the dialog
 //!     dialog_window(ctx, dctx, "Confirm name")
 //!       .show(ctx, |ui| {
+//!         // follow `Dialogs::style`
+//!         ui.set_style(dctx.style.clone());
 //!         ui.label("What's your name: ");
 //!         ui.text_edit_singleline(&mut self.name);
 //!         if ui.button("Done").clicked() {
@@ -334,10 +336,44 @@
 //! # }
 //! ```
 
+#[cfg(feature = "accesskit")]
+mod accessibility;
 mod dialog_details;
 mod dialogs;
+#[cfg(feature = "fluent")]
+mod fluent;
+mod form;
+#[cfg(test)]
+mod harness;
+mod icons;
+mod locale;
+mod markup;
+mod placement;
+mod presentation;
+#[cfg(feature = "serde")]
+mod spec;
 mod standard_dialog;
+mod theme;
+mod transition;
+mod translation;
+mod wizard;
 
+#[cfg(feature = "accesskit")]
+pub use accessibility::*;
 pub use dialog_details::*;
 pub use dialogs::*;
+#[cfg(feature = "fluent")]
+pub use fluent::*;
+pub use form::*;
+pub use icons::*;
+pub use locale::*;
+pub use markup::*;
+pub use placement::*;
+pub use presentation::*;
+#[cfg(feature = "serde")]
+pub use spec::*;
 pub use standard_dialog::*;
+pub use theme::*;
+pub use transition::*;
+pub use translation::*;
+pub use wizard::*;
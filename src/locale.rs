@@ -0,0 +1,329 @@
+//! Negotiate which of the available translations to use for the requested locales.
+
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+use sys_locale::get_locales;
+
+use crate::*;
+
+/// The locale used when none of the requested locales is available.
+pub const FALLBACK_LOCALE: &str = "en-US";
+
+/// The parts of a BCP-47 language tag used for matching, in lowercase.
+#[derive(Debug, PartialEq, Eq)]
+struct LanguageTag {
+    language: String,
+    script: Option<String>,
+    region: Option<String>,
+}
+
+impl LanguageTag {
+    /// Parse a language tag, also accepting POSIX locales like `zh_CN.UTF-8`.
+    /// Variants and extensions are ignored.
+    fn parse(tag: &str) -> Option<Self> {
+        let tag = tag.split(['.', '@']).next().unwrap_or_default();
+        let mut subtags = tag.split(['-', '_']).map(str::to_ascii_lowercase);
+
+        let language = subtags.next()?;
+        if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return None;
+        }
+
+        let mut script = None;
+        let mut region = None;
+        for subtag in subtags {
+            let is_alphabetic = subtag.chars().all(|c| c.is_ascii_alphabetic());
+            let is_numeric = subtag.chars().all(|c| c.is_ascii_digit());
+            if script.is_none() && region.is_none() && subtag.len() == 4 && is_alphabetic {
+                script = Some(subtag);
+            } else if region.is_none()
+                && ((subtag.len() == 2 && is_alphabetic) || (subtag.len() == 3 && is_numeric))
+            {
+                region = Some(subtag);
+            } else {
+                break;
+            }
+        }
+
+        // languages written in several scripts imply one from the region
+        if script.is_none() {
+            script = likely_script(&language, region.as_deref()).map(str::to_owned);
+        }
+
+        Some(Self {
+            language,
+            script,
+            region,
+        })
+    }
+
+    /// Whether the scripts of both tags don't contradict each other.
+    fn script_compatible(&self, other: &Self) -> bool {
+        match (&self.script, &other.script) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        }
+    }
+}
+
+/// The script implied by a language and region if the language has several scripts.
+fn likely_script(language: &str, region: Option<&str>) -> Option<&'static str> {
+    match (language, region) {
+        ("zh", Some("tw" | "hk" | "mo")) => Some("hant"),
+        ("zh", _) => Some("hans"),
+        ("sr", Some("me")) => Some("latn"),
+        ("sr", _) => Some("cyrl"),
+        _ => None,
+    }
+}
+
+/// The direction in which dialogs are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LayoutDirection {
+    #[default]
+    LeftToRight,
+    /// Mirror the dialogs for right-to-left scripts like Arabic and Hebrew.
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    /// The direction of the script the locale is written in.
+    ///
+    /// # Example
+    /// ```
+    /// use egui_dialogs::LayoutDirection;
+    ///
+    /// assert_eq!(LayoutDirection::of_locale("ar-EG"), LayoutDirection::RightToLeft);
+    /// assert_eq!(LayoutDirection::of_locale("he_IL.UTF-8"), LayoutDirection::RightToLeft);
+    /// assert_eq!(LayoutDirection::of_locale("pa-Arab"), LayoutDirection::RightToLeft);
+    /// assert_eq!(LayoutDirection::of_locale("fr-FR"), LayoutDirection::LeftToRight);
+    /// assert_eq!(LayoutDirection::of_locale("C"), LayoutDirection::LeftToRight);
+    /// ```
+    pub fn of_locale(locale: &str) -> Self {
+        let Some(tag) = LanguageTag::parse(locale) else {
+            return Self::LeftToRight;
+        };
+
+        let is_rtl = match tag.script.as_deref() {
+            Some(script) => matches!(script, "arab" | "hebr" | "syrc" | "thaa" | "nkoo" | "adlm"),
+            None => matches!(
+                tag.language.as_str(),
+                "ar" | "he" | "iw" | "fa" | "ur" | "ps" | "yi" | "dv" | "sd" | "ug" | "ckb"
+            ),
+        };
+        if is_rtl {
+            Self::RightToLeft
+        } else {
+            Self::LeftToRight
+        }
+    }
+
+    /// The direction of the locale the built-in texts are translated into.
+    /// See [`set_locale`].
+    pub fn of_active_locale() -> Self {
+        Self::of_locale(active_locale())
+    }
+
+    #[inline]
+    pub fn is_rtl(self) -> bool {
+        self == Self::RightToLeft
+    }
+}
+
+/// Pick one of the available locales for the requested locales in order of preference.
+///
+/// For each requested locale, an available locale is looked up with the same
+/// language, script and region, then with the same language and script,
+/// and then with the same language. The first requested locale with a match wins.
+/// If none matches, `fallback` is returned.
+///
+/// Scripts are implied by the region for languages written in several scripts,
+/// so `zh-Hans-CN` matches `zh-CN` and `zh-HK` matches `zh-TW`.
+///
+/// # Example
+/// ```
+/// use egui_dialogs::negotiate_locale;
+///
+/// let available = ["en-US", "zh-CN", "zh-TW", "es", "fr", "ja", "pt-BR"];
+/// assert_eq!(negotiate_locale(["fr-FR", "en-US"], &available, "en-US"), "fr");
+/// assert_eq!(negotiate_locale(["de-DE"], &available, "en-US"), "en-US");
+/// ```
+pub fn negotiate_locale<'a, S: AsRef<str>>(
+    requested: impl IntoIterator<Item = S>,
+    available: &[&'a str],
+    fallback: &'a str,
+) -> &'a str {
+    let available: Vec<_> = available
+        .iter()
+        .filter_map(|locale| LanguageTag::parse(locale).map(|tag| (tag, *locale)))
+        .collect();
+
+    for requested in requested {
+        let Some(requested) = LanguageTag::parse(requested.as_ref()) else {
+            continue;
+        };
+
+        let exact = available.iter().find(|(tag, _)| *tag == requested);
+        let script = || {
+            available.iter().find(|(tag, _)| {
+                tag.language == requested.language
+                    && tag.script.is_some()
+                    && tag.script == requested.script
+            })
+        };
+        let language = || {
+            available.iter().find(|(tag, _)| {
+                tag.language == requested.language && tag.script_compatible(&requested)
+            })
+        };
+
+        if let Some((_, locale)) = exact.or_else(script).or_else(language) {
+            return locale;
+        }
+    }
+
+    fallback
+}
+
+/// The locales of the system in order of preference.
+/// Queried only once.
+pub fn system_locales() -> &'static [String] {
+    static LOCALES: OnceLock<Vec<String>> = OnceLock::new();
+    LOCALES.get_or_init(|| get_locales().collect())
+}
+
+static LOCALE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Force the locale of the built-in translations at runtime.
+/// Use None to follow the system locales.
+///
+/// # Example
+/// ```
+/// use egui_dialogs::{set_locale, StandardReply};
+///
+/// set_locale(Some("fr-FR".into()));
+/// assert_eq!(StandardReply::Cancel.localize(), "Annuler");
+///
+/// set_locale(Some("zh-Hant-TW".into()));
+/// assert_eq!(StandardReply::Ok.localize(), "確定");
+/// # set_locale(None);
+/// ```
+pub fn set_locale(locale: Option<String>) {
+    *LOCALE.write().unwrap_or_else(PoisonError::into_inner) = locale;
+    reset_active_locale();
+}
+
+/// The locale set with [`set_locale`] if there is one.
+pub fn locale() -> Option<String> {
+    LOCALE
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// The locales to translate into in order of preference:
+/// the locale set with [`set_locale`], or the system locales.
+pub fn preferred_locales() -> Vec<String> {
+    match locale() {
+        Some(locale) => vec![locale],
+        None => system_locales().to_vec(),
+    }
+}
+
+// kept until `set_locale` or `set_translator` changes it, to not parse all the tags every frame
+static ACTIVE_LOCALE: RwLock<Option<&'static str>> = RwLock::new(None);
+
+/// The locale of the built-in translations picked for the preferred locales.
+/// Negotiated again after [`set_locale`] or [`set_translator`].
+pub(crate) fn active_locale() -> &'static str {
+    if let Some(locale) = *ACTIVE_LOCALE.read().unwrap_or_else(PoisonError::into_inner) {
+        return locale;
+    }
+
+    let locales = StandardTranslator::locales();
+    let locale = negotiate_locale(preferred_locales(), &locales, FALLBACK_LOCALE);
+    *ACTIVE_LOCALE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = Some(locale);
+    locale
+}
+
+/// Negotiate the active locale again the next time it's needed.
+pub(crate) fn reset_active_locale() {
+    *ACTIVE_LOCALE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harness::Harness;
+
+    const AVAILABLE: [&str; 7] = ["en-US", "zh-CN", "zh-TW", "es", "fr", "ja", "pt-BR"];
+
+    fn negotiate(requested: &[&str]) -> &'static str {
+        negotiate_locale(requested, &AVAILABLE, FALLBACK_LOCALE)
+    }
+
+    #[test]
+    fn exact_matches_ignore_case_and_separators() {
+        assert_eq!(negotiate(&["pt-BR"]), "pt-BR");
+        assert_eq!(negotiate(&["zh_cn"]), "zh-CN");
+    }
+
+    #[test]
+    fn language_and_script_match_before_language() {
+        assert_eq!(negotiate(&["zh-Hans-CN"]), "zh-CN");
+        assert_eq!(negotiate(&["zh-Hant-HK"]), "zh-TW");
+        assert_eq!(negotiate(&["zh-HK"]), "zh-TW");
+        assert_eq!(negotiate(&["zh-SG"]), "zh-CN");
+    }
+
+    #[test]
+    fn language_matches_any_region() {
+        assert_eq!(negotiate(&["fr-FR"]), "fr");
+        assert_eq!(negotiate(&["es-MX"]), "es");
+        assert_eq!(negotiate(&["ja-JP"]), "ja");
+        assert_eq!(negotiate(&["pt-PT"]), "pt-BR");
+        assert_eq!(negotiate(&["en-GB"]), "en-US");
+        assert_eq!(negotiate(&["fr_CA.UTF-8"]), "fr");
+    }
+
+    #[test]
+    fn scripts_must_not_contradict() {
+        assert_eq!(
+            negotiate_locale(["zh-Hant"], &["en-US", "zh-CN"], "en-US"),
+            "en-US"
+        );
+    }
+
+    #[test]
+    fn preference_order_wins_over_match_quality() {
+        assert_eq!(negotiate(&["de-DE", "fr-CA", "es-ES"]), "fr");
+    }
+
+    #[test]
+    fn unmatched_locales_fall_back() {
+        assert_eq!(negotiate(&["de-DE"]), "en-US");
+        assert_eq!(negotiate(&["C", ""]), "en-US");
+        assert_eq!(negotiate(&[]), "en-US");
+    }
+
+    #[test]
+    fn the_active_locale_follows_set_locale() {
+        let _harness = Harness::new(Dialogs::new());
+        set_locale(Some("ar-EG".into()));
+        assert_eq!(
+            LayoutDirection::of_active_locale(),
+            LayoutDirection::RightToLeft
+        );
+        set_locale(Some("fr-FR".into()));
+        assert_eq!(
+            LayoutDirection::of_active_locale(),
+            LayoutDirection::LeftToRight
+        );
+        assert_eq!(translate("close"), "Fermer la fenêtre");
+    }
+}
@@ -0,0 +1,540 @@
+//! A lightweight markup for the content of dialogs.
+
+use egui::{vec2, Align, FontFamily, Id, Layout, RichText, Ui};
+
+use crate::*;
+
+/// Text with lightweight, Markdown-style formatting.
+///
+/// - `**bold**`, `*italic*` or `_italic_`, and `` `code` ``
+/// - `[links](https://example.com)`, opened with `ctx.open_url`
+/// - lines starting with `- ` or `* ` for bullet lists, and with `1. ` for numbered lists,
+///   continued by lines starting with any number, so that `2024. ` alone stays a paragraph
+/// - blank lines between paragraphs
+///
+/// Markers are escaped with a backslash, and are shown as they are when they aren't closed.
+///
+/// # Example
+/// ```
+/// use egui_dialogs::{DialogDetails, Markup, StandardDialog};
+///
+/// let markup = Markup::new(
+///     "**Careful!** This will delete:\n\
+///      - your `settings.toml`\n\
+///      - *all* your saved games\n\
+///      \n\
+///      [Learn more](https://example.com/backups)",
+/// );
+/// assert_eq!(markup.blocks().len(), 4);
+///
+/// let dialog = StandardDialog::warning("Reset", "").markup(markup);
+/// # let mut dialogs = egui_dialogs::Dialogs::new();
+/// DialogDetails::new(dialog).show(&mut dialogs);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Markup {
+    blocks: Vec<MarkupBlock>,
+}
+
+/// A paragraph or a list item of [`Markup`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkupBlock {
+    Paragraph(Vec<MarkupSpan>),
+    Bullet(Vec<MarkupSpan>),
+    Numbered(u32, Vec<MarkupSpan>),
+}
+
+/// A run of text of [`Markup`] with the same formatting.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MarkupSpan {
+    pub text: String,
+    pub bold: bool,
+    pub italic: bool,
+    pub code: bool,
+    /// The url the span links to.
+    pub link: Option<String>,
+}
+
+impl Markup {
+    /// Parse the markup.
+    pub fn new(source: impl AsRef<str>) -> Self {
+        let mut blocks = vec![];
+        // the kind of the block being built, and its text
+        let mut current: Option<(BlockKind, String)> = None;
+        // whether a numbered list goes on, even after blank lines
+        let mut in_numbered_list = false;
+        let mut flush = |current: &mut Option<(BlockKind, String)>| {
+            if let Some((kind, text)) = current.take() {
+                blocks.push(kind.block(parse_spans(&text)));
+            }
+        };
+
+        for line in source.as_ref().lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                flush(&mut current);
+                continue;
+            }
+
+            if let Some((kind, text)) = list_item(line, in_numbered_list) {
+                flush(&mut current);
+                in_numbered_list = matches!(kind, BlockKind::Numbered(_));
+                current = Some((kind, text.to_owned()));
+            } else if let Some((_, text)) = &mut current {
+                // continue the paragraph or the list item
+                text.push(' ');
+                text.push_str(line);
+            } else {
+                in_numbered_list = false;
+                current = Some((BlockKind::Paragraph, line.to_owned()));
+            }
+        }
+        flush(&mut current);
+
+        Self { blocks }
+    }
+
+    /// The paragraphs and list items of the markup.
+    pub fn blocks(&self) -> &[MarkupBlock] {
+        &self.blocks
+    }
+
+    /// The text without the formatting.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for block in &self.blocks {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            let spans = match block {
+                MarkupBlock::Paragraph(spans) => spans,
+                MarkupBlock::Bullet(spans) => {
+                    text.push_str("• ");
+                    spans
+                }
+                MarkupBlock::Numbered(number, spans) => {
+                    text.push_str(&format!("{number}. "));
+                    spans
+                }
+            };
+            for span in spans {
+                text.push_str(&span.text);
+            }
+        }
+        text
+    }
+
+    /// Show the markup, laid out in the direction.
+    /// Returns the ids of its labels and links,
+    /// to describe a dialog with them for assistive technologies.
+    pub fn show(&self, ui: &mut Ui, direction: LayoutDirection) -> Vec<Id> {
+        let mut ids = vec![];
+        let paragraph_spacing = ui.spacing().item_spacing.y;
+        let layout = if direction.is_rtl() {
+            Layout::right_to_left(Align::Min)
+        } else {
+            Layout::left_to_right(Align::Min)
+        };
+
+        for (index, block) in self.blocks.iter().enumerate() {
+            let (marker, spans) = match block {
+                MarkupBlock::Paragraph(spans) => {
+                    if index > 0 {
+                        ui.add_space(paragraph_spacing);
+                    }
+                    (None, spans)
+                }
+                MarkupBlock::Bullet(spans) => (Some("•".to_owned()), spans),
+                MarkupBlock::Numbered(number, spans) => (Some(format!("{number}.")), spans),
+            };
+
+            // rows as high as a line, like `Ui::horizontal_wrapped`,
+            // so that they don't take the whole height left
+            let row = vec2(ui.available_width(), ui.spacing().interact_size.y);
+            ui.allocate_ui_with_layout(row, layout, |ui| {
+                if let Some(marker) = marker {
+                    ui.label(marker);
+                }
+                // the spans wrap together after the marker
+                let row = vec2(ui.available_width(), ui.spacing().interact_size.y);
+                ui.allocate_ui_with_layout(row, layout.with_main_wrap(true), |ui| {
+                    ui.spacing_mut().item_spacing.x = 0.;
+                    for span in spans {
+                        ids.push(show_span(ui, span));
+                    }
+                });
+            });
+        }
+
+        ids
+    }
+}
+
+impl From<&str> for Markup {
+    fn from(source: &str) -> Self {
+        Self::new(source)
+    }
+}
+
+impl From<String> for Markup {
+    fn from(source: String) -> Self {
+        Self::new(source)
+    }
+}
+
+/// Show a span as a label or a link, and return its id.
+fn show_span(ui: &mut Ui, span: &MarkupSpan) -> Id {
+    let mut text = RichText::new(&span.text);
+    if span.bold {
+        text = text.strong();
+    }
+    if span.italic {
+        text = text.italics();
+    }
+    if span.code {
+        // the family overrides the font of the dialog
+        text = text.code().family(FontFamily::Monospace);
+    }
+
+    match &span.link {
+        Some(url) => ui.hyperlink_to(text, url).id,
+        None => ui.label(text).id,
+    }
+}
+
+#[derive(Clone, Copy)]
+enum BlockKind {
+    Paragraph,
+    Bullet,
+    Numbered(u32),
+}
+
+impl BlockKind {
+    fn block(self, spans: Vec<MarkupSpan>) -> MarkupBlock {
+        match self {
+            Self::Paragraph => MarkupBlock::Paragraph(spans),
+            Self::Bullet => MarkupBlock::Bullet(spans),
+            Self::Numbered(number) => MarkupBlock::Numbered(number, spans),
+        }
+    }
+}
+
+/// Split a list item like `- text` or `1. text` into its kind and its text.
+/// Numbered lists start at 1, other numbers only continue them.
+fn list_item(line: &str, in_numbered_list: bool) -> Option<(BlockKind, &str)> {
+    if let Some(text) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Some((BlockKind::Bullet, text));
+    }
+    let (number, text) = line.split_once(". ")?;
+    let number = number.parse().ok()?;
+    (number == 1 || in_numbered_list).then_some((BlockKind::Numbered(number), text))
+}
+
+/// The characters escaped with a backslash.
+const ESCAPABLE: &str = "\\*_`[]";
+
+/// Parse the inline formatting of a block.
+fn parse_spans(text: &str) -> Vec<MarkupSpan> {
+    let chars: Vec<char> = text.chars().collect();
+    let is_escape =
+        |i: usize| chars[i] == '\\' && chars.get(i + 1).is_some_and(|c| ESCAPABLE.contains(*c));
+    // find the closing marker, skipping escaped characters,
+    // and code spans unless the marker closes one
+    let find = |from: usize, marker: &str| -> Option<usize> {
+        let marker: Vec<char> = marker.chars().collect();
+        let mut i = from;
+        while i < chars.len() {
+            if chars[i..].starts_with(&marker) {
+                return Some(i);
+            }
+            if is_escape(i) {
+                i += 2;
+            } else if chars[i] == '`' && marker != ['`'] {
+                // an unclosed backtick is just a character
+                let end = (i + 1..chars.len()).find(|&j| chars[j] == '`');
+                i = end.unwrap_or(i) + 1;
+            } else {
+                i += 1;
+            }
+        }
+        None
+    };
+    let is_word = |i: usize| chars.get(i).is_some_and(|c| c.is_alphanumeric());
+
+    let mut spans = vec![];
+    let mut span = MarkupSpan::default();
+    let mut flush = |span: &mut MarkupSpan| {
+        if !span.text.is_empty() {
+            spans.push(span.clone());
+            span.text.clear();
+        }
+    };
+    // the marker the italic text was opened with
+    let mut italic_marker = None;
+    // where the text of the link ends, and where its url ends
+    let mut link_end = None;
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+
+        if is_escape(i) {
+            span.text.push(chars[i + 1]);
+            i += 2;
+            continue;
+        }
+
+        if span.code {
+            if c == '`' {
+                flush(&mut span);
+                span.code = false;
+            } else {
+                span.text.push(c);
+            }
+            i += 1;
+            continue;
+        }
+
+        if let Some((text_end, url_end)) = link_end {
+            if i >= text_end {
+                flush(&mut span);
+                span.link = None;
+                link_end = None;
+                i = url_end + 1;
+                continue;
+            }
+        }
+
+        if c == '`' && find(i + 1, "`").is_some() {
+            flush(&mut span);
+            span.code = true;
+            i += 1;
+        } else if chars[i..].starts_with(&['*', '*']) && (span.bold || find(i + 2, "**").is_some())
+        {
+            flush(&mut span);
+            span.bold = !span.bold;
+            i += 2;
+        } else if chars[i..].starts_with(&['*', '*']) && italic_marker.is_none() {
+            // an unclosed bold marker is shown as it is
+            span.text.push_str("**");
+            i += 2;
+        } else if (c == '*' || c == '_')
+            && italic_marker == Some(c)
+            && (c == '*' || !is_word(i + 1))
+        {
+            flush(&mut span);
+            span.italic = false;
+            italic_marker = None;
+            i += 1;
+        } else if (c == '*' || c == '_')
+            && italic_marker.is_none()
+            && (c == '*' || i == 0 || !is_word(i - 1))
+            && chars
+                .get(i + 1)
+                .is_some_and(|c| !c.is_whitespace() && *c != '*')
+            && find(i + 1, &c.to_string()).is_some()
+        {
+            flush(&mut span);
+            span.italic = true;
+            italic_marker = Some(c);
+            i += 1;
+        } else if let Some(ends) = (c == '[' && link_end.is_none())
+            .then(|| {
+                let text_end = find(i + 1, "](")?;
+                let url_end = find(text_end + 2, ")")?;
+                Some((text_end, url_end))
+            })
+            .flatten()
+        {
+            flush(&mut span);
+            span.link = Some(chars[ends.0 + 2..ends.1].iter().collect());
+            link_end = Some(ends);
+            i += 1;
+        } else {
+            span.text.push(c);
+            i += 1;
+        }
+    }
+    flush(&mut span);
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(text: &str) -> MarkupSpan {
+        MarkupSpan {
+            text: text.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn bold(text: &str) -> MarkupSpan {
+        MarkupSpan {
+            bold: true,
+            ..plain(text)
+        }
+    }
+
+    fn italic(text: &str) -> MarkupSpan {
+        MarkupSpan {
+            italic: true,
+            ..plain(text)
+        }
+    }
+
+    fn code(text: &str) -> MarkupSpan {
+        MarkupSpan {
+            code: true,
+            ..plain(text)
+        }
+    }
+
+    #[test]
+    fn bold_text() {
+        assert_eq!(
+            parse_spans("a **bold** move"),
+            [plain("a "), bold("bold"), plain(" move")]
+        );
+    }
+
+    #[test]
+    fn italic_text() {
+        assert_eq!(
+            parse_spans("*very* _much_ so"),
+            [italic("very"), plain(" "), italic("much"), plain(" so")]
+        );
+        // underscores within words are kept
+        assert_eq!(parse_spans("snake_case_name"), [plain("snake_case_name")]);
+    }
+
+    #[test]
+    fn bold_and_italic() {
+        assert_eq!(
+            parse_spans("**bold *and italic***"),
+            [
+                bold("bold "),
+                MarkupSpan {
+                    italic: true,
+                    ..bold("and italic")
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn code_text() {
+        assert_eq!(
+            parse_spans("open `**settings**`"),
+            [plain("open "), code("**settings**")]
+        );
+    }
+
+    #[test]
+    fn escaped_markers() {
+        assert_eq!(
+            parse_spans(r"\*not italic\* \`not code\` \\"),
+            [plain(r"*not italic* `not code` \")]
+        );
+    }
+
+    #[test]
+    fn link_text() {
+        assert_eq!(
+            parse_spans("see [the docs](https://example.com) now"),
+            [
+                plain("see "),
+                MarkupSpan {
+                    link: Some("https://example.com".to_owned()),
+                    ..plain("the docs")
+                },
+                plain(" now"),
+            ]
+        );
+    }
+
+    #[test]
+    fn escaped_brackets_do_not_end_links() {
+        assert_eq!(parse_spans(r"[a\](b) tail"), [plain("[a](b) tail")]);
+        assert_eq!(
+            parse_spans(r"[a\] b](url) tail"),
+            [
+                MarkupSpan {
+                    link: Some("url".to_owned()),
+                    ..plain("a] b")
+                },
+                plain(" tail"),
+            ]
+        );
+    }
+
+    #[test]
+    fn code_spans_do_not_end_links() {
+        assert_eq!(
+            parse_spans("[see `a](b)` here](url) tail"),
+            [
+                MarkupSpan {
+                    link: Some("url".to_owned()),
+                    ..plain("see ")
+                },
+                MarkupSpan {
+                    link: Some("url".to_owned()),
+                    ..code("a](b)")
+                },
+                MarkupSpan {
+                    link: Some("url".to_owned()),
+                    ..plain(" here")
+                },
+                plain(" tail"),
+            ]
+        );
+        assert_eq!(parse_spans("`a](b)` tail"), [code("a](b)"), plain(" tail")]);
+    }
+
+    #[test]
+    fn unclosed_markers() {
+        assert_eq!(
+            parse_spans("2 * 3 **not bold `nor code [nor a link"),
+            [plain("2 * 3 **not bold `nor code [nor a link")]
+        );
+    }
+
+    #[test]
+    fn lists() {
+        let markup = Markup::new("Steps:\n- one\n* two\n\n1. first\n2. second\n\n3. third");
+        assert_eq!(
+            markup.blocks(),
+            [
+                MarkupBlock::Paragraph(vec![plain("Steps:")]),
+                MarkupBlock::Bullet(vec![plain("one")]),
+                MarkupBlock::Bullet(vec![plain("two")]),
+                MarkupBlock::Numbered(1, vec![plain("first")]),
+                MarkupBlock::Numbered(2, vec![plain("second")]),
+                MarkupBlock::Numbered(3, vec![plain("third")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn numbers_outside_of_lists_are_text() {
+        let markup = Markup::new("2024. was a good year\n\n1. first\nParagraph\n\n2. second");
+        assert_eq!(
+            markup.blocks(),
+            [
+                MarkupBlock::Paragraph(vec![plain("2024. was a good year")]),
+                MarkupBlock::Numbered(1, vec![plain("first Paragraph")]),
+                MarkupBlock::Numbered(2, vec![plain("second")]),
+            ]
+        );
+        let markup = Markup::new("Intro\n\n2. not a list");
+        assert_eq!(
+            markup.blocks(),
+            [
+                MarkupBlock::Paragraph(vec![plain("Intro")]),
+                MarkupBlock::Paragraph(vec![plain("2. not a list")]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Where dialogs are placed on the screen.
+
+use egui::{Align2, Pos2, Rect, Vec2};
+
+/// Where a dialog is placed.
+///
+/// The point of the dialog given by the pivot is put at a position
+/// computed from the mask rect or given on the screen.
+///
+/// # Example
+/// ```
+/// use egui::{pos2, Align2};
+/// use egui_dialogs::{DialogDetails, DialogPlacement, Dialogs};
+///
+/// // all dialogs hang from the top of the mask
+/// let mut dialogs = Dialogs::new().placement(DialogPlacement::top_sheet());
+///
+/// // except this one, in the bottom right corner
+/// DialogDetails::info("Saved", "Your work is safe")
+///     .with_placement(DialogPlacement::aligned(Align2::RIGHT_BOTTOM, [-16., -16.]))
+///     .show(&mut dialogs);
+///
+/// // the pivot of the dialog is put at the position
+/// let placement = DialogPlacement::relative([0.25, 0.5], Align2::CENTER_CENTER);
+/// let (pivot, pos) = placement.pivot_pos(egui::Rect::from_min_size(pos2(0., 0.), [800., 600.].into()));
+/// assert_eq!(pivot, Align2::CENTER_CENTER);
+/// assert_eq!(pos, pos2(200., 300.));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DialogPlacement {
+    /// Aligned within the mask rect and moved by the offset.
+    Aligned { align: Align2, offset: Vec2 },
+
+    /// With the pivot of the dialog at a position on the screen.
+    Fixed { pos: Pos2, pivot: Align2 },
+
+    /// With the pivot of the dialog at a position relative to the mask rect,
+    /// from `[0, 0]` at its top left corner to `[1, 1]` at its bottom right corner.
+    Relative { pos: Vec2, pivot: Align2 },
+}
+
+impl DialogPlacement {
+    /// In the center of the mask rect.
+    pub fn center() -> Self {
+        Self::aligned(Align2::CENTER_CENTER, Vec2::ZERO)
+    }
+
+    /// At the top center of the mask rect, like a sheet hanging from the title bar.
+    pub fn top_sheet() -> Self {
+        Self::aligned(Align2::CENTER_TOP, Vec2::ZERO)
+    }
+
+    /// Aligned within the mask rect and moved by the offset.
+    pub fn aligned(align: Align2, offset: impl Into<Vec2>) -> Self {
+        Self::Aligned {
+            align,
+            offset: offset.into(),
+        }
+    }
+
+    /// With the pivot of the dialog at a position on the screen.
+    pub fn fixed(pos: impl Into<Pos2>, pivot: Align2) -> Self {
+        Self::Fixed {
+            pos: pos.into(),
+            pivot,
+        }
+    }
+
+    /// With the pivot of the dialog at a position relative to the mask rect.
+    pub fn relative(pos: impl Into<Vec2>, pivot: Align2) -> Self {
+        Self::Relative {
+            pos: pos.into(),
+            pivot,
+        }
+    }
+
+    /// The pivot of the dialog and the position to put it at.
+    pub fn pivot_pos(&self, mask_rect: Rect) -> (Align2, Pos2) {
+        match *self {
+            Self::Aligned { align, offset } => (align, align.pos_in_rect(&mask_rect) + offset),
+            Self::Fixed { pos, pivot } => (pivot, pos),
+            Self::Relative { pos, pivot } => (pivot, mask_rect.lerp_inside(pos)),
+        }
+    }
+
+    /// The rect of a dialog of the size.
+    pub fn rect(&self, size: Vec2, mask_rect: Rect) -> Rect {
+        let (pivot, pos) = self.pivot_pos(mask_rect);
+        pivot.anchor_size(pos, size)
+    }
+}
+
+impl Default for DialogPlacement {
+    fn default() -> Self {
+        Self::center()
+    }
+}
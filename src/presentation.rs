@@ -0,0 +1,123 @@
+//! How dialogs are presented: as windows, sheets or drawers.
+
+use egui::{panel::Side, Align2, CornerRadius, Shadow, Vec2};
+
+use crate::*;
+
+/// How a dialog is presented.
+///
+/// Sheets and drawers are attached to an edge of the mask rect,
+/// with a frame open on that side and a transition sliding them in from it.
+/// Windows created with [`dialog_window`] follow the presentation automatically,
+/// and custom dialogs can read it from [`DialogContext::presentation`].
+///
+/// # Example
+/// ```
+/// use egui::panel::Side;
+/// use egui_dialogs::{DialogDetails, DialogPresentation, Dialogs};
+///
+/// let mut dialogs = Dialogs::new();
+///
+/// // slide up from the bottom of the screen, across its whole width
+/// DialogDetails::confirm("Share", "Share this photo with your friends?")
+///     .with_presentation(DialogPresentation::BottomSheet)
+///     .show(&mut dialogs);
+///
+/// // slide in from the left side, along its whole height
+/// DialogDetails::info("Menu", "Nothing here yet")
+///     .with_presentation(DialogPresentation::Drawer(Side::Left))
+///     .show(&mut dialogs);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DialogPresentation {
+    /// A window placed following the [`DialogPlacement`].
+    #[default]
+    Window,
+
+    /// A sheet sliding down from the top of the mask rect, like from the title bar.
+    TopSheet,
+
+    /// A sheet sliding up from the bottom of the mask rect, across its whole width.
+    BottomSheet,
+
+    /// A drawer sliding in from a side of the mask rect, along its whole height.
+    Drawer(Side),
+
+    /// The whole mask rect, sliding up from the bottom.
+    /// Dialogs are shown full screen on narrow screens, see [`Dialogs::full_screen_width`],
+    /// and standard dialogs stack their buttons with bigger touch targets.
+    FullScreen,
+}
+
+impl DialogPresentation {
+    /// Where the dialog is placed, if the presentation decides it.
+    pub fn placement(&self) -> Option<DialogPlacement> {
+        match self {
+            Self::Window => None,
+            Self::TopSheet => Some(DialogPlacement::top_sheet()),
+            Self::BottomSheet => Some(DialogPlacement::aligned(Align2::CENTER_BOTTOM, Vec2::ZERO)),
+            Self::Drawer(Side::Left) => {
+                Some(DialogPlacement::aligned(Align2::LEFT_TOP, Vec2::ZERO))
+            }
+            Self::Drawer(Side::Right) => {
+                Some(DialogPlacement::aligned(Align2::RIGHT_TOP, Vec2::ZERO))
+            }
+            Self::FullScreen => Some(DialogPlacement::aligned(Align2::LEFT_TOP, Vec2::ZERO)),
+        }
+    }
+
+    /// The transition of the dialog, if the presentation has one.
+    /// A transition set for the dialog itself takes precedence,
+    /// and the duration is the one of [`Dialogs::transition`].
+    pub fn transition(&self) -> Option<DialogTransition> {
+        match self {
+            Self::Window => None,
+            Self::TopSheet => Some(DialogTransition::slide_from_top()),
+            Self::BottomSheet => Some(DialogTransition::slide_from_bottom()),
+            Self::Drawer(Side::Left) => Some(DialogTransition::slide_from_left()),
+            Self::Drawer(Side::Right) => Some(DialogTransition::slide_from_right()),
+            Self::FullScreen => Some(DialogTransition::slide_from_bottom()),
+        }
+    }
+
+    /// Adapt the frame of the dialog to the presentation,
+    /// without rounding on the edge it is attached to.
+    pub fn frame(&self, frame: egui::Frame) -> egui::Frame {
+        let radius = frame.corner_radius;
+        let corner_radius = match self {
+            Self::Window => radius,
+            Self::TopSheet => CornerRadius {
+                nw: 0,
+                ne: 0,
+                ..radius
+            },
+            Self::BottomSheet => CornerRadius {
+                sw: 0,
+                se: 0,
+                ..radius
+            },
+            Self::Drawer(Side::Left) => CornerRadius {
+                nw: 0,
+                sw: 0,
+                ..radius
+            },
+            Self::Drawer(Side::Right) => CornerRadius {
+                ne: 0,
+                se: 0,
+                ..radius
+            },
+            Self::FullScreen => return frame.corner_radius(0).shadow(Shadow::NONE),
+        };
+        frame.corner_radius(corner_radius)
+    }
+
+    /// Whether the dialog takes the whole width of the mask rect.
+    pub fn fills_width(&self) -> bool {
+        matches!(self, Self::BottomSheet | Self::FullScreen)
+    }
+
+    /// Whether the dialog takes the whole height of the mask rect.
+    pub fn fills_height(&self) -> bool {
+        matches!(self, Self::Drawer(_) | Self::FullScreen)
+    }
+}
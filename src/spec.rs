@@ -0,0 +1,311 @@
+//! Describe standard dialogs in data, e.g. in config files or messages from a server.
+
+use egui::Vec2;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// A standard dialog described in data.
+/// Converts into a [`StandardDialog`] or a [`DialogDetails`].
+///
+/// Missing fields take their default values,
+/// and dialogs without buttons get the buttons of their severity.
+///
+/// # Example
+/// ```
+/// use egui_dialogs::{DialogDetails, DialogSeverity, StandardDialogSpec, StandardReply};
+///
+/// let json = r#"{
+///     "id": "update",
+///     "title": "Update available",
+///     "content": "Version **2.0** is ready to install.",
+///     "markup": true,
+///     "severity": "info",
+///     "buttons": [
+///         { "reply": "yes", "text": "Install now" },
+///         { "reply": "no" }
+///     ],
+///     "cancel_button": 1,
+///     "min_size": { "x": 320.0, "y": 0.0 }
+/// }"#;
+/// let spec: StandardDialogSpec = serde_json::from_str(json).unwrap();
+/// assert_eq!(spec.severity, Some(DialogSeverity::Info));
+/// assert_eq!(spec.buttons[0].reply, StandardReply::Yes);
+/// assert_eq!(spec.min_size, Some(egui::vec2(320., 0.)));
+/// assert_eq!(spec.max_size, None);
+///
+/// // round trips through JSON and RON
+/// let json = serde_json::to_string(&spec).unwrap();
+/// assert_eq!(serde_json::from_str::<StandardDialogSpec>(&json).unwrap(), spec);
+/// let ron = ron::to_string(&spec).unwrap();
+/// assert_eq!(ron::from_str::<StandardDialogSpec>(&ron).unwrap(), spec);
+///
+/// # let mut dialogs = egui_dialogs::Dialogs::new();
+/// DialogDetails::from(spec)
+///     .on_reply(|reply| reply == StandardReply::Yes)
+///     .show(&mut dialogs);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StandardDialogSpec {
+    /// The id of the dialog, see [`DialogDetails::with_id`].
+    pub id: Option<String>,
+    pub title: String,
+    pub content: String,
+    /// Whether the content is [`Markup`].
+    pub markup: bool,
+    pub severity: Option<DialogSeverity>,
+    /// The buttons, or the buttons of the severity if there are none.
+    pub buttons: Vec<StandardButtonSpec>,
+    /// The index of the default button, or the one of the severity if None.
+    pub default_button: Option<usize>,
+    /// The index of the cancel button, or the one of the severity if None.
+    pub cancel_button: Option<usize>,
+    pub min_size: Option<Vec2>,
+    pub max_size: Option<Vec2>,
+}
+
+/// A button of a [`StandardDialogSpec`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StandardButtonSpec {
+    pub reply: StandardReply,
+    /// The text of the button, or the translation of the reply if None.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+impl From<StandardReply> for StandardButtonSpec {
+    fn from(reply: StandardReply) -> Self {
+        Self { reply, text: None }
+    }
+}
+
+impl StandardDialogSpec {
+    pub fn new(title: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<StandardDialogSpec> for StandardDialog<'_, StandardReply> {
+    fn from(spec: StandardDialogSpec) -> Self {
+        let (title, content) = (spec.title, spec.content);
+        let mut dialog = match spec.severity {
+            Some(DialogSeverity::Info) => StandardDialog::info(title, ""),
+            Some(DialogSeverity::Success) => StandardDialog::success(title, ""),
+            Some(DialogSeverity::Confirm) => StandardDialog::confirm(title, ""),
+            Some(DialogSeverity::Warning) => StandardDialog::warning(title, ""),
+            Some(DialogSeverity::Error) => StandardDialog::error(title, ""),
+            None => StandardDialog::new(title, "").buttons(vec![StandardReply::Ok.into()]),
+        };
+
+        dialog = if spec.markup {
+            dialog.markup(content)
+        } else {
+            dialog.content(content)
+        };
+
+        if !spec.buttons.is_empty() {
+            let buttons = spec
+                .buttons
+                .into_iter()
+                .map(|button| match button.text {
+                    Some(text) => (text.into(), button.reply),
+                    None => button.reply.into(),
+                })
+                .collect();
+            dialog = dialog.buttons(buttons);
+        }
+        if spec.default_button.is_some() {
+            dialog = dialog.default_button(spec.default_button);
+        }
+        if spec.cancel_button.is_some() {
+            dialog = dialog.cancel_button(spec.cancel_button);
+        }
+        if let Some(min_size) = spec.min_size {
+            dialog = dialog.min_size(min_size);
+        }
+        if let Some(max_size) = spec.max_size {
+            dialog = dialog.max_size(max_size);
+        }
+
+        dialog
+    }
+}
+
+/// Dialogs built from a spec with an id are persistable:
+/// they are part of [`Dialogs::snapshot`] until a reply handler is set.
+impl<'a> From<StandardDialogSpec> for DialogDetails<'a, StandardReply> {
+    fn from(spec: StandardDialogSpec) -> Self {
+        let id = spec.id.clone();
+        let mut details = DialogDetails::new(StandardDialog::from(spec.clone()));
+        if let Some(id) = id {
+            details = details.with_id(id);
+        }
+        details.spec = Some(spec);
+        details
+    }
+}
+
+impl Dialogs<'_> {
+    /// The persistable dialogs waiting in the queue, including the one shown,
+    /// to show them again with [`Dialogs::restore`] after the app restarts.
+    ///
+    /// Dialogs are persistable when they are built from a [`StandardDialogSpec`] with an id
+    /// and without a reply handler, so their replies are handled by id.
+    /// Custom dialogs and other dialogs are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use egui_dialogs::{DialogDetails, DialogSeverity, Dialogs, StandardDialogSpec};
+    ///
+    /// # #[derive(Default)]
+    /// # struct MemoryStorage(HashMap<String, String>);
+    /// # impl eframe::Storage for MemoryStorage {
+    /// #     fn get_string(&self, key: &str) -> Option<String> { self.0.get(key).cloned() }
+    /// #     fn set_string(&mut self, key: &str, value: String) { self.0.insert(key.into(), value); }
+    /// #     fn flush(&mut self) {}
+    /// # }
+    /// # let mut storage = MemoryStorage::default();
+    /// let mut dialogs = Dialogs::new();
+    /// let mut spec = StandardDialogSpec::new("Update", "The update is downloaded. Restart now?");
+    /// spec.id = Some("restart".into());
+    /// spec.severity = Some(DialogSeverity::Confirm);
+    /// DialogDetails::from(spec).show(&mut dialogs);
+    /// // dialogs without an id can't be told apart after a restart
+    /// DialogDetails::from(StandardDialogSpec::new("Hello", "Welcome back")).show(&mut dialogs);
+    /// DialogDetails::info("Saved", "Your changes are saved").show(&mut dialogs);
+    ///
+    /// // in `eframe::App::save`
+    /// eframe::set_value(&mut storage, "dialogs", &dialogs.snapshot());
+    ///
+    /// // when the app starts again
+    /// let mut dialogs = Dialogs::new();
+    /// if let Some(snapshot) = eframe::get_value::<Vec<StandardDialogSpec>>(&storage, "dialogs") {
+    ///     dialogs.restore(snapshot);
+    /// }
+    /// assert_eq!(dialogs.dialogs().len(), 1);
+    /// assert!(dialogs.is_open("restart"));
+    /// ```
+    pub fn snapshot(&self) -> Vec<StandardDialogSpec> {
+        self.dialogs()
+            .iter()
+            .filter_map(|dialog| dialog.spec().cloned())
+            .collect()
+    }
+
+    /// Queue the dialogs of a [`Dialogs::snapshot`],
+    /// unless dialogs with the same ids are already open.
+    pub fn restore(&mut self, snapshot: impl IntoIterator<Item = StandardDialogSpec>) {
+        for spec in snapshot {
+            DialogDetails::from(spec).show_if_absent(self);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use egui::{vec2, Id};
+
+    use super::*;
+
+    fn update_spec() -> StandardDialogSpec {
+        StandardDialogSpec {
+            id: Some("update".into()),
+            title: "Update available".into(),
+            content: "Version **2.0** is ready to install.".into(),
+            markup: true,
+            severity: Some(DialogSeverity::Info),
+            buttons: vec![
+                StandardButtonSpec {
+                    reply: StandardReply::Yes,
+                    text: Some("Install now".into()),
+                },
+                StandardReply::No.into(),
+            ],
+            default_button: Some(0),
+            cancel_button: Some(1),
+            min_size: Some(vec2(320., 0.)),
+            max_size: Some(vec2(640., 480.)),
+        }
+    }
+
+    #[test]
+    fn specs_round_trip_through_json() {
+        let spec = update_spec();
+        let json = serde_json::to_string(&spec).unwrap();
+        assert_eq!(
+            serde_json::from_str::<StandardDialogSpec>(&json).unwrap(),
+            spec
+        );
+
+        let spec = StandardDialogSpec::new("Hello", "Welcome back");
+        let json = serde_json::to_string(&spec).unwrap();
+        assert_eq!(
+            serde_json::from_str::<StandardDialogSpec>(&json).unwrap(),
+            spec
+        );
+    }
+
+    #[test]
+    fn specs_round_trip_through_ron() {
+        let spec = update_spec();
+        let ron = ron::to_string(&spec).unwrap();
+        assert_eq!(ron::from_str::<StandardDialogSpec>(&ron).unwrap(), spec);
+
+        let spec = StandardDialogSpec::new("Hello", "Welcome back");
+        let ron = ron::to_string(&spec).unwrap();
+        assert_eq!(ron::from_str::<StandardDialogSpec>(&ron).unwrap(), spec);
+    }
+
+    #[test]
+    fn missing_fields_take_their_defaults() {
+        let spec = StandardDialogSpec::new("Hello", "");
+        assert_eq!(
+            serde_json::from_str::<StandardDialogSpec>(r#"{ "title": "Hello" }"#).unwrap(),
+            spec
+        );
+        assert_eq!(
+            ron::from_str::<StandardDialogSpec>(r#"(title: "Hello")"#).unwrap(),
+            spec
+        );
+
+        // buttons without a text use the translation of their reply
+        let json = r#"{ "buttons": [{ "reply": "yes" }] }"#;
+        let spec = serde_json::from_str::<StandardDialogSpec>(json).unwrap();
+        assert_eq!(spec.buttons, [StandardReply::Yes.into()]);
+        assert_eq!(spec.default_button, None);
+        assert_eq!(spec.min_size, None);
+    }
+
+    #[test]
+    fn dialogs_keep_the_spec() {
+        let dialog = StandardDialog::from(update_spec());
+        let replies: Vec<_> = dialog.buttons.iter().map(|(_, reply)| *reply).collect();
+        assert_eq!(replies, [StandardReply::Yes, StandardReply::No]);
+        // the other texts follow the locale
+        assert_eq!(dialog.buttons[0].0.text(), "Install now");
+        assert_eq!(dialog.default_button, Some(0));
+        assert_eq!(dialog.cancel_button, Some(1));
+        assert_eq!(dialog.min_size, vec2(320., 0.));
+        assert_eq!(dialog.max_size, vec2(640., 480.));
+
+        let details = DialogDetails::from(update_spec());
+        assert_eq!(details.id(), Some(Id::new("update")));
+        assert_eq!(details.spec(), Some(&update_spec()));
+
+        // without sizes the dialog isn't constrained
+        let dialog = StandardDialog::from(StandardDialogSpec::new("Hello", "Welcome back"));
+        assert_eq!(dialog.min_size, Vec2::ZERO);
+        assert_eq!(dialog.max_size, Vec2::INFINITY);
+        assert_eq!(
+            DialogDetails::from(StandardDialogSpec::new("Hello", "")).id(),
+            None
+        );
+    }
+}
@@ -1,99 +1,19 @@
+use std::sync::Arc;
+
 use egui::{
-    include_image, vec2, Align, Align2, FontId, Image, ImageSource, Label, Layout, ScrollArea,
-    Vec2, WidgetText,
+    emath::{GuiRounding, NumExt},
+    vec2, Align, Button, FocusDirection, Id, Image, ImageSource, InnerResponse, Label, LayerId,
+    Layout, Order, Pos2, Rect, RichText, ScrollArea, Sense, Shape, Stroke, StrokeKind, TextStyle,
+    Ui, Vec2, WidgetInfo, WidgetText, WidgetType,
 };
-use sys_locale::get_locales;
 
 use crate::*;
 
-// standard dialog icons
-const ICON_INFO: ImageSource = include_image!("assets/info.svg");
-const ICON_SUCCESS: ImageSource = include_image!("assets/success.svg");
-const ICON_CONFIRM: ImageSource = include_image!("assets/confirm.svg");
-const ICON_WARNING: ImageSource = include_image!("assets/warning.svg");
-const ICON_ERROR: ImageSource = include_image!("assets/error.svg");
-
-// we offer the same language supports as those offered by rust-lang.org
-type StandardReplyTranslation = [(&'static str, &'static str); 10];
-
-const STANDARD_OK_REPLY: StandardReplyTranslation = [
-    ("en-US", "OK"),
-    ("zh-CN", "确定"),
-    ("zh-TW", "確定"),
-    ("es", "OK"),
-    ("fr", "D'accord"),
-    ("it", "OK"),
-    ("ja", "はい"),
-    ("pt-BR", "OK"),
-    ("ru", "Хорошо"),
-    ("tr", "Tamam"),
-];
-
-const STANDARD_CANCEL_REPLY: StandardReplyTranslation = [
-    ("en-US", "Cancel"),
-    ("zh-CN", "取消"),
-    ("zh-TW", "取消"),
-    ("es", "Cancelar"),
-    ("fr", "Annuler"),
-    ("it", "Annulla"),
-    ("ja", "キャンセル"),
-    ("pt-BR", "Cancelar"),
-    ("ru", "Отмена"),
-    ("tr", "İptal"),
-];
-
-const STANDARD_YES_REPLY: StandardReplyTranslation = [
-    ("en-US", "Yes"),
-    ("zh-CN", "是"),
-    ("zh-TW", "是"),
-    ("es", "Sí"),
-    ("fr", "Oui"),
-    ("it", "Sì"),
-    ("ja", "はい"),
-    ("pt-BR", "Sim"),
-    ("ru", "Да"),
-    ("tr", "Evet"),
-];
-
-const STANDARD_NO_REPLY: [(&'static str, &'static str); 10] = [
-    ("en-US", "No"),
-    ("zh-CN", "否"),
-    ("zh-TW", "否"),
-    ("es", "No"),
-    ("fr", "Non"),
-    ("it", "No"),
-    ("ja", "いいえ"),
-    ("pt-BR", "Não"),
-    ("ru", "Нет"),
-    ("tr", "Hayır"),
-];
-
-#[inline]
-fn find_translation(source: StandardReplyTranslation, locale: String) -> Option<String> {
-    for (locale_key, reply) in source {
-        if locale_key == locale {
-            return Some(reply.to_string());
-        }
-    }
-
-    None
-}
-
-#[inline]
-fn translate_standard_reply(source: StandardReplyTranslation) -> String {
-    let locales = get_locales();
-    for locale in locales {
-        if let Some(reply) = find_translation(source, locale) {
-            return reply;
-        }
-    }
-
-    source[0].1.to_string()
-}
-
 /// Standard dialog replies.
 /// Can be translated to the current locale.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum StandardReply {
     Ok,
     Cancel,
@@ -102,29 +22,30 @@ pub enum StandardReply {
 }
 
 impl StandardReply {
+    /// Translate the reply with the current translator.
+    /// See [`set_translator`].
     pub fn localize(self) -> String {
+        translate(self.key())
+    }
+
+    /// The key of the reply text for translators.
+    pub fn key(self) -> &'static str {
         match self {
-            StandardReply::Ok => translate_standard_reply(STANDARD_OK_REPLY),
-            StandardReply::Cancel => translate_standard_reply(STANDARD_CANCEL_REPLY),
-            StandardReply::Yes => translate_standard_reply(STANDARD_YES_REPLY),
-            StandardReply::No => translate_standard_reply(STANDARD_NO_REPLY),
+            StandardReply::Ok => "ok",
+            StandardReply::Cancel => "cancel",
+            StandardReply::Yes => "yes",
+            StandardReply::No => "no",
         }
     }
 
     #[inline]
     pub fn accepted(self) -> bool {
-        match self {
-            StandardReply::Ok | StandardReply::Yes => true,
-            _ => false,
-        }
+        matches!(self, StandardReply::Ok | StandardReply::Yes)
     }
 
     #[inline]
     pub fn rejected(self) -> bool {
-        match self {
-            StandardReply::Cancel | StandardReply::No => true,
-            _ => false,
-        }
+        matches!(self, StandardReply::Cancel | StandardReply::No)
     }
 }
 
@@ -134,15 +55,94 @@ impl From<StandardReply> for StandardButton<StandardReply> {
     }
 }
 
-impl ToString for StandardReply {
-    fn to_string(&self) -> String {
-        self.localize()
+impl std::fmt::Display for StandardReply {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.localize())
+    }
+}
+
+/// Severity of a dialog.
+/// Exposed to assistive technologies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum DialogSeverity {
+    Info,
+    Success,
+    Confirm,
+    Warning,
+    Error,
+}
+
+impl DialogSeverity {
+    /// Whether the dialog needs the user's immediate attention.
+    #[inline]
+    pub fn is_alert(self) -> bool {
+        matches!(self, DialogSeverity::Warning | DialogSeverity::Error)
+    }
+
+    /// The lowercase name of the severity.
+    pub fn name(self) -> &'static str {
+        match self {
+            DialogSeverity::Info => "info",
+            DialogSeverity::Success => "success",
+            DialogSeverity::Confirm => "confirm",
+            DialogSeverity::Warning => "warning",
+            DialogSeverity::Error => "error",
+        }
     }
 }
 
 /// A standard dialog button with text and a reply
 pub type StandardButton<Reply> = (WidgetText, Reply);
 
+/// A function showing custom widgets in the body of a standard dialog.
+pub type DialogBody<'i> = dyn FnMut(&mut Ui, &mut DialogBodyState) + 'i;
+
+/// The state of the buttons of a standard dialog, updated by its body every frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialogBodyState {
+    enabled: Vec<bool>,
+    just_opened: bool,
+}
+
+impl DialogBodyState {
+    /// All buttons enabled.
+    pub fn new(button_count: usize) -> Self {
+        Self {
+            enabled: vec![true; button_count],
+            just_opened: false,
+        }
+    }
+
+    /// Whether the dialog is shown for the first time in this frame.
+    /// A widget of the body given the focus then keeps it,
+    /// instead of the default button.
+    #[inline]
+    pub fn just_opened(&self) -> bool {
+        self.just_opened
+    }
+
+    /// The number of buttons of the dialog.
+    #[inline]
+    pub fn button_count(&self) -> usize {
+        self.enabled.len()
+    }
+
+    /// Whether the button at the index is enabled.
+    #[inline]
+    pub fn is_enabled(&self, index: usize) -> bool {
+        self.enabled.get(index).copied().unwrap_or(false)
+    }
+
+    /// Enable or disable the button at the index for this frame.
+    pub fn set_enabled(&mut self, index: usize, enabled: bool) {
+        if let Some(slot) = self.enabled.get_mut(index) {
+            *slot = enabled;
+        }
+    }
+}
+
 /// A standard dialog.
 /// Use `Dialogs::info`, `Dialogs::warn`, ...
 /// to directly show a standard dialog.
@@ -152,8 +152,19 @@ pub type StandardButton<Reply> = (WidgetText, Reply);
 pub struct StandardDialog<'i, Reply> {
     pub title: WidgetText,
     pub content: WidgetText,
+    /// Formatted content shown instead of `content` if there is one.
+    pub markup: Option<Markup>,
+    /// Custom widgets shown instead of the content if there are some.
+    pub body: Option<Box<DialogBody<'i>>>,
     pub image: Option<ImageSource<'i>>,
+    pub severity: Option<DialogSeverity>,
     pub buttons: Vec<StandardButton<Reply>>,
+    /// Index of the button triggered by the accept key
+    /// and focused when the dialog opens.
+    pub default_button: Option<usize>,
+    /// Index of the button triggered by the cancel key or the close button.
+    /// If None, the last button is used.
+    pub cancel_button: Option<usize>,
     pub min_size: Vec2,
     pub max_size: Vec2,
 }
@@ -167,8 +178,13 @@ where
         Self {
             title: title.into(),
             content: content.into(),
+            markup: None,
+            body: None,
             image: None,
+            severity: None,
             buttons: vec![],
+            default_button: Some(0),
+            cancel_button: None,
             min_size: Vec2::ZERO,
             max_size: Vec2::INFINITY,
         }
@@ -188,13 +204,63 @@ where
         self
     }
 
-    /// Set the dialog image
+    /// Set the formatted dialog content, shown instead of the plain content
+    #[inline]
+    pub fn markup(mut self, markup: impl Into<Markup>) -> Self {
+        self.markup = Some(markup.into());
+        self
+    }
+
+    /// Show custom widgets in the body of the dialog, instead of the content.
+    /// The title, the icon, the buttons and the keys work as usual,
+    /// and the body can enable or disable the buttons every frame.
+    /// While a text edit of the body has the focus, it keeps the arrow and Enter keys.
+    /// Disabled buttons can't be triggered by the accept key,
+    /// but closing the dialog still replies with the cancel button.
+    ///
+    /// # Example
+    /// ```
+    /// use std::{cell::Cell, rc::Rc};
+    /// use egui_dialogs::{DialogDetails, StandardDialog, StandardReply};
+    ///
+    /// let agreed = Rc::new(Cell::new(false));
+    /// let dialog = StandardDialog::confirm("License", "").with_body({
+    ///     let agreed = Rc::clone(&agreed);
+    ///     move |ui, state| {
+    ///         ui.label("Do you accept the terms of the license?");
+    ///         let mut checked = agreed.get();
+    ///         ui.checkbox(&mut checked, "I have read the terms");
+    ///         agreed.set(checked);
+    ///         // "Yes" only once the box is checked
+    ///         state.set_enabled(0, checked);
+    ///     }
+    /// });
+    ///
+    /// # let mut dialogs = egui_dialogs::Dialogs::new();
+    /// DialogDetails::new(dialog)
+    ///     .on_reply(move |reply| reply == StandardReply::Yes && agreed.get())
+    ///     .show(&mut dialogs);
+    /// ```
+    #[inline]
+    pub fn with_body(mut self, body: impl FnMut(&mut Ui, &mut DialogBodyState) + 'i) -> Self {
+        self.body = Some(Box::new(body));
+        self
+    }
+
+    /// Set the dialog image, shown instead of the icon of its severity
     #[inline]
     pub fn image(mut self, image: ImageSource<'i>) -> Self {
         self.image = Some(image);
         self
     }
 
+    /// Set the dialog severity
+    #[inline]
+    pub fn severity(mut self, severity: DialogSeverity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
     /// Set the dialog buttons
     #[inline]
     pub fn buttons(mut self, buttons: Vec<StandardButton<Reply>>) -> Self {
@@ -209,6 +275,20 @@ where
         self
     }
 
+    /// Set the index of the default button
+    #[inline]
+    pub fn default_button(mut self, index: Option<usize>) -> Self {
+        self.default_button = index;
+        self
+    }
+
+    /// Set the index of the cancel button
+    #[inline]
+    pub fn cancel_button(mut self, index: Option<usize>) -> Self {
+        self.cancel_button = index;
+        self
+    }
+
     /// Set the minimum size of the dialog
     #[inline]
     pub fn min_size(mut self, min_size: Vec2) -> Self {
@@ -231,8 +311,13 @@ impl<'i> StandardDialog<'i, StandardReply> {
         Self {
             title: title.into(),
             content: content.into(),
-            image: Some(ICON_INFO),
+            markup: None,
+            body: None,
+            image: None,
+            severity: Some(DialogSeverity::Info),
             buttons: vec![StandardReply::Ok.into()],
+            default_button: Some(0),
+            cancel_button: None,
             min_size: Vec2::ZERO,
             max_size: Vec2::INFINITY,
         }
@@ -243,8 +328,13 @@ impl<'i> StandardDialog<'i, StandardReply> {
         Self {
             title: title.into(),
             content: content.into(),
-            image: Some(ICON_SUCCESS),
+            markup: None,
+            body: None,
+            image: None,
+            severity: Some(DialogSeverity::Success),
             buttons: vec![StandardReply::Ok.into()],
+            default_button: Some(0),
+            cancel_button: None,
             min_size: Vec2::ZERO,
             max_size: Vec2::INFINITY,
         }
@@ -255,8 +345,13 @@ impl<'i> StandardDialog<'i, StandardReply> {
         Self {
             title: title.into(),
             content: content.into(),
-            image: Some(ICON_CONFIRM),
+            markup: None,
+            body: None,
+            image: None,
+            severity: Some(DialogSeverity::Confirm),
             buttons: vec![StandardReply::Yes.into(), StandardReply::No.into()],
+            default_button: Some(0),
+            cancel_button: Some(1),
             min_size: Vec2::ZERO,
             max_size: Vec2::INFINITY,
         }
@@ -267,8 +362,13 @@ impl<'i> StandardDialog<'i, StandardReply> {
         Self {
             title: title.into(),
             content: content.into(),
-            image: Some(ICON_WARNING),
+            markup: None,
+            body: None,
+            image: None,
+            severity: Some(DialogSeverity::Warning),
             buttons: vec![StandardReply::Ok.into()],
+            default_button: Some(0),
+            cancel_button: None,
             min_size: Vec2::ZERO,
             max_size: Vec2::INFINITY,
         }
@@ -279,8 +379,13 @@ impl<'i> StandardDialog<'i, StandardReply> {
         Self {
             title: title.into(),
             content: content.into(),
-            image: Some(ICON_ERROR),
+            markup: None,
+            body: None,
+            image: None,
+            severity: Some(DialogSeverity::Error),
             buttons: vec![StandardReply::Ok.into()],
+            default_button: Some(0),
+            cancel_button: None,
             min_size: Vec2::ZERO,
             max_size: Vec2::INFINITY,
         }
@@ -292,101 +397,343 @@ where
     Reply: Clone,
 {
     fn show(&mut self, ctx: &egui::Context, dctx: &DialogContext) -> Option<Reply> {
+        let mut body = self.body.take();
+        let reply = self.show_with_body(ctx, dctx, body.as_deref_mut());
+        self.body = body;
+        reply
+    }
+}
+
+impl<'i, Reply> StandardDialog<'i, Reply>
+where
+    Reply: Clone,
+{
+    /// Show the dialog with the body instead of its own,
+    /// for dialogs built on standard dialogs to show their widgets.
+    pub(crate) fn show_with_body(
+        &mut self,
+        ctx: &egui::Context,
+        dctx: &DialogContext,
+        body: Option<&mut DialogBody<'_>>,
+    ) -> Option<Reply> {
+        let mut reply = None;
+        let mut open = true;
+        let mut content_ids = vec![];
+        let mut state = DialogBodyState::new(self.buttons.len());
+        state.just_opened = dctx.just_opened;
+
+        if dctx.anchor.is_some() {
+            dialog_popover(ctx, dctx, self.title.clone(), &mut open, |ui| {
+                ui.set_min_width(self.min_size.x);
+                ui.set_max_width(ui.max_rect().width().min(self.max_size.x));
+                (reply, content_ids) = self.show_contents(ui, dctx, body, &mut state);
+            });
+        } else {
+            // the sizes of the dialog give way to the presentation
+            let mut theme = dctx.theme.clone();
+            if let Some(severity) = self.severity.filter(|_| theme.accent_stroke) {
+                theme.stroke.color = theme.accent(severity);
+            }
+            let dctx = &DialogContext {
+                theme,
+                min_size: Some(self.min_size.max(dctx.min_size.unwrap_or(Vec2::ZERO))),
+                max_size: Some(
+                    self.max_size
+                        .min(dctx.max_size.unwrap_or(dctx.mask_rect.size())),
+                ),
+                ..dctx.clone()
+            };
+            closable_dialog_window(ctx, dctx, self.title.clone(), &mut open).show(ctx, |ui| {
+                if dctx.presentation.fills_height() {
+                    // windows shrink to their contents vertically
+                    ui.set_min_height(ui.max_rect().height());
+                }
+                (reply, content_ids) = self.show_contents(ui, dctx, body, &mut state);
+            });
+        }
+
+        #[cfg(feature = "accesskit")]
+        for content_id in content_ids {
+            describe_dialog(ctx, self.severity, content_id);
+        }
+        #[cfg(not(feature = "accesskit"))]
+        let _ = content_ids;
+
+        if let Some(reply_value) = reply {
+            Some(reply_value)
+        } else if !open {
+            self.cancel_button
+                .and_then(|i| self.buttons.get(i))
+                .or(self.buttons.last())
+                .map(|(_, reply_value)| reply_value.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Show the icon, the content and the buttons, and handle the keys of the buttons.
+    /// Returns the reply if a button was triggered and the ids of the content labels.
+    fn show_contents(
+        &self,
+        ui: &mut Ui,
+        dctx: &DialogContext,
+        body: Option<&mut DialogBody<'_>>,
+        state: &mut DialogBodyState,
+    ) -> (Option<Reply>, Vec<Id>) {
         let Self {
-            title,
             content,
+            markup,
             image,
+            severity,
             buttons,
-            min_size,
-            max_size,
+            default_button,
+            ..
         } = self;
 
         let mut reply = None;
-        let mut open = true;
+        let mut content_ids = vec![];
+        // to tell whether the body has taken the focus
+        let focused_before = ui.memory(|m| m.focused());
 
-        closable_dialog_window(ctx, dctx, title.clone(), &mut open)
-            // explicitly center the dialog as our button layout depends on this
-            .anchor(Align2::CENTER_CENTER, [0., 0.])
-            .min_size(min_size.max(dctx.min_size.unwrap_or(Vec2::ZERO)))
-            .max_size(max_size.min(dctx.max_size.unwrap_or(dctx.mask_rect.size())))
-            .show(ctx, |ui| {
-                ui.style_mut().override_font_id = Some(FontId::proportional(16.0));
-
-                ui.horizontal_top(|ui| {
-                    const IMAGE_WIDTH: f32 = 48.;
-                    
-                    if let Some(image) = image {
-                        ui.add(
-                            Image::new(image.clone())
-                                .fit_to_exact_size(vec2(IMAGE_WIDTH, IMAGE_WIDTH)),
-                        );
-                    }
+        let theme = &dctx.theme;
+        ui.set_style(Arc::clone(&dctx.style));
+        ui.style_mut().override_font_id = Some(theme.body_font.clone());
 
-                    let text_height = ui
-                        .style()
-                        .text_styles
-                        .get(&egui::TextStyle::Button)
-                        .map(|f| f.size)
-                        .unwrap_or(20.)
-                        * 1.5;
-                    let max_height = ui.max_rect().height()
-                        - ui.spacing().item_spacing.y
-                        - ui.spacing().button_padding.y * 2.
-                        - text_height;
-                    ScrollArea::vertical()
-                        .auto_shrink([true, true])
-                        .max_height(max_height)
-                        .show(ui, |ui| {
-                            ui.add(Label::new(content.clone()).wrap());
-                        });
-                });
+        // windows don't shrink to their contents, so we keep the width of the last frame,
+        // unless the user sets the width
+        let width_id = ui.id().with("__contents_width");
+        let last_width = if dctx.resizable || dctx.presentation.fills_width() {
+            Some(ui.available_width())
+        } else {
+            ui.data(|d| d.get_temp::<f32>(width_id))
+        }
+        .filter(|_| !ui.is_sizing_pass());
+        // lay out the contents at their natural size until we know their width
+        let is_natural = last_width.is_none();
+        let width = last_width.map_or(ui.available_width(), |width| {
+            width.min(ui.available_width())
+        });
 
-                let layout = if ui.is_sizing_pass() {
-                    Layout::left_to_right(Align::Min)
-                } else {
-                    // infer available width for the buttons from the dialog's position
-                    ui.set_max_width(
-                        (ctx.content_rect().center().x - ui.next_widget_position().x).abs() * 2.,
-                    );
-                    Layout::right_to_left(Align::Min)
-                };
-                ui.with_layout(layout, |ui| {
-                    for (text, reply_value) in buttons.iter().rev() {
-                        if ui.button(text.clone()).clicked() {
-                            reply = Some(reply_value.clone());
-                            break;
-                        }
+        // the icon and the buttons start from the right in right-to-left dialogs
+        let is_rtl = dctx.direction.is_rtl() && !is_natural;
+        // full screen dialogs stack their buttons at the bottom, easier to reach and tap
+        let is_stacked = dctx.presentation == DialogPresentation::FullScreen;
+        const TOUCH_TARGET_HEIGHT: f32 = 44.;
+
+        let layout = if is_rtl {
+            Layout::right_to_left(Align::Min)
+        } else {
+            Layout::left_to_right(Align::Min)
+        };
+        // the whole height left, like `Ui::horizontal_top`, for the scroll area to grow into
+        ui.allocate_ui_with_layout(vec2(width, ui.available_height()), layout, |ui| {
+            if let Some(image) = image {
+                ui.add(Image::new(image.clone()).fit_to_exact_size(Vec2::splat(theme.icon_size)));
+            } else if let Some(severity) = severity {
+                theme
+                    .icons
+                    .show(ui, *severity, theme.icon_size, theme.accent(*severity));
+            }
+
+            let text_height = ui
+                .style()
+                .text_styles
+                .get(&egui::TextStyle::Button)
+                .map(|f| f.size)
+                .unwrap_or(20.)
+                * 1.5;
+            let max_height = if is_stacked {
+                ui.max_rect().height()
+                    - buttons.len() as f32 * (theme.button_spacing + TOUCH_TARGET_HEIGHT)
+            } else {
+                ui.max_rect().height()
+                    - ui.spacing().item_spacing.y
+                    - ui.spacing().button_padding.y * 2.
+                    - text_height
+            };
+            ScrollArea::vertical()
+                .auto_shrink([true, true])
+                .max_height(max_height)
+                .show(ui, |ui| {
+                    let halign = if dctx.direction.is_rtl() {
+                        Align::RIGHT
+                    } else {
+                        Align::LEFT
+                    };
+                    if let Some(body) = body {
+                        let response = ui.with_layout(Layout::top_down(halign), |ui| {
+                            body(ui, state);
+                        });
+                        content_ids.push(response.response.id);
+                    } else if let Some(markup) = markup {
+                        ui.with_layout(Layout::top_down(halign), |ui| {
+                            content_ids = markup.show(ui, dctx.direction);
+                        });
+                    } else {
+                        let label = Label::new(content.clone()).wrap().halign(halign);
+                        content_ids.push(ui.add(label).id);
                     }
                 });
-            });
+        });
 
-        if let Some(reply_value) = reply {
-            Some(reply_value.clone())
-        } else if !open {
-            buttons.last().map(|(_, reply_value)| reply_value.clone())
+        let layout = if is_stacked {
+            // the first button on top
+            Layout::bottom_up(Align::Center).with_cross_justify(true)
+        } else if is_natural || is_rtl {
+            Layout::left_to_right(Align::Min)
         } else {
-            None
+            Layout::right_to_left(Align::Min)
+        };
+        if !is_natural {
+            ui.set_max_width(width);
         }
+        ui.with_layout(layout, |ui| {
+            ui.spacing_mut().item_spacing = Vec2::splat(theme.button_spacing);
+            let mut responses = Vec::with_capacity(buttons.len());
+            for (index, (text, reply_value)) in buttons.iter().enumerate().rev() {
+                let mut button = Button::new(text.clone());
+                if is_stacked {
+                    button = button.min_size(vec2(0., TOUCH_TARGET_HEIGHT));
+                }
+                let response = ui.add_enabled(state.is_enabled(index), button);
+                if response.has_focus() {
+                    ui.painter().rect_stroke(
+                        response.rect.expand(2.),
+                        ui.visuals().widgets.active.corner_radius,
+                        ui.visuals().selection.stroke,
+                        StrokeKind::Outside,
+                    );
+                }
+                if response.clicked() {
+                    reply = Some(reply_value.clone());
+                    break;
+                }
+                responses.push(response);
+            }
+            // restore the order of the buttons
+            responses.reverse();
+
+            if reply.is_some() || dctx.already_closed {
+                return;
+            }
+
+            // the keys go to the buttons, unless a widget of the body like a text edit
+            // has the focus, which the body has drawn before, or a popup like a combo box is open
+            let has_keys = !egui::Popup::is_any_open(ui.ctx())
+                && ui
+                    .memory(|m| m.focused())
+                    .is_none_or(|id| responses.iter().any(|response| response.id == id));
+            let mut focus_step = 0;
+            if has_keys {
+                if DialogKeys::consume(ui.ctx(), &dctx.keys.previous) {
+                    focus_step = -1;
+                } else if DialogKeys::consume(ui.ctx(), &dctx.keys.next) {
+                    focus_step = 1;
+                }
+                if focus_step != 0 {
+                    // keep egui from moving the focus on its own
+                    ui.memory_mut(|m| m.move_focus(FocusDirection::None));
+                }
+
+                if DialogKeys::consume(ui.ctx(), &dctx.keys.accept) {
+                    reply = default_button
+                        .filter(|&i| state.is_enabled(i))
+                        .and_then(|i| buttons.get(i))
+                        .map(|(_, reply_value)| reply_value.clone());
+                }
+            }
+
+            // disabled buttons are skipped
+            let enabled: Vec<usize> = (0..responses.len())
+                .filter(|&i| state.is_enabled(i))
+                .collect();
+            let focused = enabled.iter().position(|&i| responses[i].has_focus());
+            let to_focus = if dctx.just_opened {
+                let focused_by_body = ui
+                    .memory(|m| m.focused())
+                    .is_some_and(|id| Some(id) != focused_before);
+                default_button.filter(|&i| !focused_by_body && state.is_enabled(i))
+            } else if focus_step != 0 && !enabled.is_empty() {
+                let count = enabled.len() as isize;
+                match focused {
+                    Some(index) => {
+                        Some(enabled[(index as isize + focus_step).rem_euclid(count) as usize])
+                    }
+                    None => default_button
+                        .filter(|&i| state.is_enabled(i))
+                        .or(enabled.first().copied()),
+                }
+            } else {
+                None
+            };
+            if let Some(response) = to_focus.and_then(|i| responses.get(i)) {
+                response.request_focus();
+            }
+        });
+
+        let width = ui.min_rect().width();
+        ui.data_mut(|d| d.insert_temp(width_id, width));
+
+        (reply, content_ids)
     }
 }
 
-/// Create a suggested dialog window
+/// Create a suggested dialog window placed following [`DialogContext::placement`]
+/// and framed following [`DialogContext::presentation`].
+/// If the dialog is modal, the keyboard focus is trapped in the window.
+///
+/// Sheets are given the whole width of the mask rect and drawers its whole height,
+/// which the contents fill with `ui.set_min_height(ui.max_rect().height())`.
+///
+/// The frame and the title follow [`DialogContext::style`], but the contents follow
+/// the style of the context unless they set it with `ui.set_style(dctx.style.clone())`.
 pub fn dialog_window<'open>(
     ctx: &egui::Context,
     dctx: &DialogContext,
     title: impl Into<WidgetText>,
 ) -> egui::Window<'open> {
-    let frame = egui::Frame::window(&ctx.style()).inner_margin(16.);
+    let frame = dialog_frame(dctx);
+
+    let title = themed_title(title.into(), dctx);
+    let id = dialog_window_id(dctx, &title);
+
+    let layer_id = LayerId::new(Order::Middle, id);
+    transition_layer(ctx, layer_id);
+
+    #[cfg(feature = "accesskit")]
+    register_dialog(ctx, layer_id, &title, dctx.modal && !dctx.already_closed);
 
-    let mut window = egui::Window::new(title.into())
+    if dctx.modal && !dctx.already_closed {
+        ctx.memory_mut(|m| {
+            m.areas_mut().move_to_top(layer_id);
+            // limit tab navigation to the dialog
+            m.set_modal_layer(layer_id);
+        });
+    }
+
+    let mut window = egui::Window::new(title.clone())
+        .id(id)
         .collapsible(false)
-        .resizable(false)
-        .anchor(Align2::CENTER_CENTER, [0., 0.])
+        .resizable(dctx.resizable)
         .frame(frame)
-        .fade_in(dctx.animation.is_some())
-        .fade_out(dctx.animation.is_some())
+        // faded by the transition
+        .fade_in(false)
+        .fade_out(false)
         .interactable(!dctx.already_closed);
 
+    if dctx.movable || dctx.resizable {
+        window = window
+            .movable(dctx.movable)
+            .constrain_to(dctx.mask_rect)
+            .max_size(dctx.mask_rect.size());
+        if let Some(pos) = remembered_window_pos(ctx, dctx, id) {
+            window = window.current_pos(pos);
+        }
+    } else {
+        let (pivot, pos) = dctx.placement.pivot_pos(dctx.mask_rect);
+        window = window.pivot(pivot).fixed_pos(pos);
+    }
+
     if let Some(min_size) = dctx.min_size {
         window = window.min_size(min_size);
     }
@@ -395,14 +742,74 @@ pub fn dialog_window<'open>(
         window = window.max_size(max_size);
     }
 
-    if let Some(id) = dctx.dialog_id {
-        window = window.id(id);
+    // sheets and drawers take the whole mask rect along their edge
+    let margins = frame.total_margin().sum()
+        + vec2(
+            0.,
+            title_bar_height(ctx, &title, &frame) + frame.stroke.width,
+        );
+    let contents_size = (dctx.mask_rect.size() - margins).max(Vec2::ZERO);
+    if dctx.presentation.fills_width() {
+        window = window.min_width(contents_size.x).max_width(contents_size.x);
+    }
+    if dctx.presentation.fills_height() {
+        window = window
+            .min_height(contents_size.y)
+            .max_height(contents_size.y);
     }
 
     window
 }
 
-/// Create a suggested dialog window with a close button
+/// The geometry of a movable or resizable dialog window where the user left it.
+fn dialog_geometry_id(window_id: Id) -> Id {
+    window_id.with("__dialog_geometry")
+}
+
+/// Remember the geometry of a movable or resizable dialog window,
+/// and return the position to move it to in this frame if there is one.
+///
+/// The window is placed following the placement until its size is known,
+/// reopens where the user left it, clamped to the mask rect,
+/// and is left to egui in between.
+fn remembered_window_pos(ctx: &egui::Context, dctx: &DialogContext, window_id: Id) -> Option<Pos2> {
+    let geometry_id = dialog_geometry_id(window_id);
+    let remembered = ctx.data_mut(|d| d.get_persisted::<Rect>(geometry_id));
+    let last_rect = ctx.memory(|m| m.area_rect(window_id));
+
+    let (pos, rect) = match remembered {
+        Some(rect) if dctx.just_opened => {
+            let rect = clamp_rect(rect, dctx.mask_rect);
+            (Some(rect.min), None)
+        }
+        Some(_) => (None, last_rect),
+        None => {
+            let size = last_rect.map_or(Vec2::ZERO, |rect| rect.size());
+            let rect = dctx.placement.rect(size, dctx.mask_rect);
+            // the size may change in the first frames
+            let is_placed = last_rect.is_some_and(|last_rect| {
+                (last_rect.min - rect.min).length() < 1. && last_rect.size() == rect.size()
+            });
+            (Some(rect.min), Some(rect).filter(|_| is_placed))
+        }
+    };
+
+    if let Some(rect) = rect.filter(|_| !dctx.just_opened && !dctx.already_closed) {
+        ctx.data_mut(|d| d.insert_persisted(geometry_id, rect));
+    }
+    pos
+}
+
+/// Move the rect into the bounds, keeping its top left corner inside if it is too large.
+fn clamp_rect(rect: Rect, bounds: Rect) -> Rect {
+    let min = rect.min.min(bounds.max - rect.size()).max(bounds.min);
+    Rect::from_min_size(min, rect.size())
+}
+
+/// Create a suggested dialog window with a close button.
+/// Pressing the cancel key also closes the window.
+/// The window stays open while the dialog transitions out.
+/// The close button is on the left in right-to-left dialogs.
 #[inline]
 pub fn closable_dialog_window<'open>(
     ctx: &egui::Context,
@@ -411,8 +818,455 @@ pub fn closable_dialog_window<'open>(
     open: &'open mut bool,
 ) -> egui::Window<'open> {
     if dctx.already_closed {
+        // keep the window while it transitions out
+        *open = true;
+    } else if !egui::Popup::is_any_open(ctx) && DialogKeys::consume(ctx, &dctx.keys.cancel) {
         *open = false;
     }
 
-    dialog_window(ctx, dctx, title).open(open)
+    let title = themed_title(title.into(), dctx);
+    let id = dialog_window_id(dctx, &title);
+    let window = dialog_window(ctx, dctx, title.clone());
+
+    // egui always puts the close button on the right, in the style of the context
+    let is_styled = !Arc::ptr_eq(&dctx.style, &ctx.style());
+    if (dctx.direction.is_rtl() || is_styled) && *open {
+        if own_close_button(ctx, dctx, &title, id) {
+            *open = false;
+        } else {
+            return window;
+        }
+    }
+
+    window.open(open)
+}
+
+/// Show a suggested popover next to [`DialogContext::anchor`],
+/// with an arrow pointing to it.
+/// The popover is placed below the anchor, or above it if there is more room there,
+/// and is kept within the mask rect.
+///
+/// Pressing the cancel key or clicking outside of the popover sets `open` to false.
+/// The cancel key closes an open popup like a combo box first.
+/// The popover stays open while the dialog transitions out.
+///
+/// # Example
+/// ```
+/// use egui_dialogs::{dialog_popover, Dialog, DialogContext};
+///
+/// struct RenamePopover {
+///     name: String,
+/// }
+///
+/// impl Dialog<Option<String>> for RenamePopover {
+///     fn show(&mut self, ctx: &egui::Context, dctx: &DialogContext) -> Option<Option<String>> {
+///         let mut open = true;
+///         let renamed = dialog_popover(ctx, dctx, "Rename", &mut open, |ui| {
+///             ui.text_edit_singleline(&mut self.name);
+///             ui.button("Rename").clicked()
+///         })
+///         .inner;
+///
+///         if renamed {
+///             Some(Some(self.name.clone()))
+///         } else if !open {
+///             Some(None)
+///         } else {
+///             None
+///         }
+///     }
+/// }
+/// ```
+pub fn dialog_popover<R>(
+    ctx: &egui::Context,
+    dctx: &DialogContext,
+    title: impl Into<WidgetText>,
+    open: &mut bool,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> InnerResponse<R> {
+    const ARROW_SIZE: f32 = 8.;
+    const MAX_WIDTH: f32 = 320.;
+
+    if dctx.already_closed {
+        // keep the popover while it transitions out
+        *open = true;
+    } else if !egui::Popup::is_any_open(ctx) && DialogKeys::consume(ctx, &dctx.keys.cancel) {
+        *open = false;
+    }
+
+    let title = title.into();
+    let id = dialog_window_id(dctx, &title);
+    let layer_id = LayerId::new(Order::Foreground, id);
+    transition_layer(ctx, layer_id);
+
+    #[cfg(feature = "accesskit")]
+    register_dialog(ctx, layer_id, &title, dctx.modal && !dctx.already_closed);
+
+    let anchor = dctx
+        .anchor
+        .unwrap_or_else(|| Rect::from_center_size(dctx.mask_rect.center(), Vec2::ZERO));
+    // placed with the size of the last frame
+    let last_rect = ctx.memory(|m| m.area_rect(id));
+    let size = last_rect.map_or(Vec2::ZERO, |rect| rect.size());
+    let placement = PopoverPlacement::new(anchor, size, dctx.mask_rect, ARROW_SIZE);
+
+    let frame = egui::Frame::popup(&dctx.style).inner_margin(12.);
+    let response = egui::Area::new(id)
+        .order(Order::Foreground)
+        .fixed_pos(placement.rect.min)
+        .constrain(false)
+        .interactable(!dctx.already_closed)
+        // faded by the transition
+        .fade_in(false)
+        .show(ctx, |ui| {
+            ui.set_style(Arc::clone(&dctx.style));
+            frame
+                .show(ui, |ui| {
+                    if let Some(min_size) = dctx.min_size {
+                        ui.set_min_size(min_size);
+                    }
+                    let max_width = dctx.max_size.map_or(MAX_WIDTH, |size| size.x);
+                    ui.set_max_width(ui.max_rect().width().min(max_width));
+
+                    if !title.is_empty() {
+                        let layout = if dctx.direction.is_rtl() && !ui.is_sizing_pass() {
+                            Layout::right_to_left(Align::Min)
+                        } else {
+                            Layout::left_to_right(Align::Min)
+                        };
+                        ui.with_layout(layout, |ui| ui.label(title.clone().strong()));
+                    }
+                    add_contents(ui)
+                })
+                .inner
+        });
+
+    // the area isn't drawn in its sizing pass
+    if last_rect.is_some() {
+        let painter = ctx.layer_painter(layer_id);
+        let (tip, base) = placement.arrow(response.response.rect, ARROW_SIZE, frame);
+        painter.add(Shape::convex_polygon(
+            vec![tip, base[0], base[1]],
+            frame.fill,
+            Stroke::NONE,
+        ));
+        painter.line_segment([base[0], tip], frame.stroke);
+        painter.line_segment([tip, base[1]], frame.stroke);
+    }
+
+    if !dctx.already_closed && !dctx.just_opened {
+        let clicked_outside = ctx.input(|i| {
+            i.pointer.any_pressed()
+                && i.pointer.interact_pos().is_some_and(|pos| {
+                    // the anchor usually toggles the popover by itself
+                    !response.response.rect.contains(pos) && !anchor.contains(pos)
+                })
+        });
+        if clicked_outside {
+            *open = false;
+        }
+    }
+
+    response
+}
+
+/// Where a popover is placed next to its anchor.
+struct PopoverPlacement {
+    /// The rect of the popover.
+    rect: Rect,
+
+    /// The anchor the popover points to.
+    anchor: Rect,
+
+    /// Whether the popover is below the anchor.
+    below: bool,
+}
+
+impl PopoverPlacement {
+    fn new(anchor: Rect, size: Vec2, mask_rect: Rect, gap: f32) -> Self {
+        let space_below = mask_rect.bottom() - anchor.bottom() - gap;
+        let space_above = anchor.top() - mask_rect.top() - gap;
+        // flip above the anchor if it doesn't fit below
+        let below = space_below >= size.y || space_below >= space_above;
+
+        let x = (anchor.center().x - size.x / 2.)
+            .at_most(mask_rect.right() - size.x)
+            .at_least(mask_rect.left());
+        let y = if below {
+            anchor.bottom() + gap
+        } else {
+            anchor.top() - gap - size.y
+        };
+
+        Self {
+            rect: Rect::from_min_size(egui::pos2(x, y), size),
+            anchor,
+            below,
+        }
+    }
+
+    /// The tip and the base of the arrow of the popover drawn in `rect`.
+    /// The base overlaps the border of the frame to hide it under the arrow.
+    fn arrow(&self, rect: Rect, size: f32, frame: egui::Frame) -> (Pos2, [Pos2; 2]) {
+        let radius = f32::from(frame.corner_radius.nw);
+        let x = self
+            .anchor
+            .center()
+            .x
+            .at_most(rect.right() - radius - size)
+            .at_least(rect.left() + radius + size);
+        let (tip_y, base_y) = if self.below {
+            (rect.top() - size, rect.top() + frame.stroke.width)
+        } else {
+            (rect.bottom() + size, rect.bottom() - frame.stroke.width)
+        };
+        (
+            egui::pos2(x, tip_y),
+            [egui::pos2(x - size, base_y), egui::pos2(x + size, base_y)],
+        )
+    }
+}
+
+/// The frame of the suggested dialog windows.
+fn dialog_frame(dctx: &DialogContext) -> egui::Frame {
+    dctx.presentation.frame(dctx.theme.frame())
+}
+
+/// The title of a dialog window in the title font of the theme,
+/// unless it is already styled.
+fn themed_title(title: WidgetText, dctx: &DialogContext) -> WidgetText {
+    match title {
+        WidgetText::Text(text) => RichText::new(text)
+            .font(dctx.theme.title_font.clone())
+            .color(dctx.style.visuals.text_color())
+            .into(),
+        // as egui does for window titles
+        title => title.fallback_text_style(TextStyle::Heading),
+    }
+}
+
+/// The height of the title bar of a window with the title and the frame,
+/// as egui computes it.
+fn title_bar_height(ctx: &egui::Context, title: &WidgetText, frame: &egui::Frame) -> f32 {
+    let style = ctx.style();
+    ctx.fonts_mut(|fonts| match title {
+        WidgetText::RichText(text) => text.font_height(fonts, &style),
+        _ => fonts.row_height(&TextStyle::Heading.resolve(&style)),
+    })
+    .round_ui()
+    .at_least(style.spacing.interact_size.y)
+        + frame.inner_margin.sum().y
+}
+
+/// The id of a dialog window.
+fn dialog_window_id(dctx: &DialogContext, title: &WidgetText) -> Id {
+    // the same id as egui gives to a window without one
+    dctx.dialog_id.unwrap_or_else(|| Id::new(title.text()))
+}
+
+/// Paint a close button in the style of the dialog in the title bar of the window,
+/// on the left in right-to-left dialogs, where the window was in the last frame.
+/// Returns whether the button was clicked.
+fn own_close_button(
+    ctx: &egui::Context,
+    dctx: &DialogContext,
+    title: &WidgetText,
+    window_id: Id,
+) -> bool {
+    let Some(window_rect) = ctx.memory(|m| m.area_rect(window_id)) else {
+        return false;
+    };
+
+    let style = &dctx.style;
+    let frame = dialog_frame(dctx);
+    let title_height = title_bar_height(ctx, title, &frame);
+    let title_rect = window_rect.shrink(frame.stroke.width);
+    let button_center = if dctx.direction.is_rtl() {
+        title_rect.left_top() + Vec2::splat(title_height / 2.)
+    } else {
+        title_rect.right_top() + vec2(-title_height / 2., title_height / 2.)
+    };
+    let button_rect = Rect::from_center_size(button_center, Vec2::splat(style.spacing.icon_width));
+
+    let area_id = window_id.with("__close_button");
+    let clicked = egui::Area::new(area_id)
+        .order(Order::Middle)
+        .fixed_pos(button_rect.min)
+        .interactable(!dctx.already_closed)
+        // faded by the transition
+        .fade_in(false)
+        .show(ctx, |ui| {
+            ui.set_style(Arc::clone(style));
+            let (rect, response) = ui.allocate_exact_size(button_rect.size(), Sense::click());
+            response.widget_info(|| {
+                WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), translate("close"))
+            });
+
+            let visuals = ui.style().interact(&response);
+            let rect = rect.shrink(2.).expand(visuals.expansion);
+            StandardIcon::Close.paint(ui.painter(), rect, visuals.fg_stroke.color);
+
+            response.clicked()
+        })
+        .inner;
+
+    // stay above the window
+    let layer_id = LayerId::new(Order::Middle, area_id);
+    ctx.memory_mut(|m| m.areas_mut().move_to_top(layer_id));
+    transition_layer(ctx, layer_id);
+
+    clicked
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use egui::Key;
+
+    use super::*;
+    use crate::harness::Harness;
+
+    /// The texts of the text edits and their ids.
+    type Fields = Rc<RefCell<Vec<(String, Id)>>>;
+
+    /// A harness without animations showing a dialog with text edits in its body,
+    /// the first one focused after the dialog is opened.
+    fn with_text_edits(multiline: bool) -> (Harness<'static>, Fields) {
+        let fields = Rc::new(RefCell::new(vec![(String::new(), Id::NULL); 2]));
+        let mut frame = 0;
+        let body = {
+            let fields = Rc::clone(&fields);
+            move |ui: &mut Ui, _: &mut DialogBodyState| {
+                frame += 1;
+                for (index, (text, id)) in fields.borrow_mut().iter_mut().enumerate() {
+                    let response = if multiline {
+                        ui.text_edit_multiline(text)
+                    } else {
+                        ui.text_edit_singleline(text)
+                    };
+                    *id = response.id;
+                    if index == 0 && frame == 2 {
+                        response.request_focus();
+                    }
+                }
+            }
+        };
+        let mut dialogs = Dialogs::new();
+        dialogs.animation = None;
+        dialogs.add(DialogDetails::new(
+            StandardDialog::confirm("Rename", "").with_body(body),
+        ));
+        let mut harness = Harness::new(dialogs);
+        harness.run();
+        harness.run();
+        (harness, fields)
+    }
+
+    fn reply(harness: &mut Harness) -> Option<StandardReply> {
+        harness.run().and_then(|response| response.reply().ok())
+    }
+
+    #[test]
+    fn bodies_can_focus_a_text_edit_when_opened() {
+        let name = Rc::new(RefCell::new(String::new()));
+        let body = {
+            let name = Rc::clone(&name);
+            move |ui: &mut Ui, state: &mut DialogBodyState| {
+                let response = ui.text_edit_singleline(&mut *name.borrow_mut());
+                if state.just_opened() {
+                    response.request_focus();
+                }
+            }
+        };
+        let mut dialogs = Dialogs::new();
+        dialogs.animation = None;
+        dialogs.add(DialogDetails::new(
+            StandardDialog::confirm("Rename", "").with_body(body),
+        ));
+        let mut harness = Harness::new(dialogs);
+        harness.run();
+
+        harness.type_text("ab");
+        harness.run();
+        harness.press(Key::ArrowLeft);
+        harness.run();
+        harness.type_text("X");
+        assert_eq!(reply(&mut harness), None);
+        assert_eq!(*name.borrow(), "aXb");
+    }
+
+    #[test]
+    fn arrows_move_the_cursor_of_a_text_edit() {
+        let (mut harness, fields) = with_text_edits(false);
+        harness.type_text("ab");
+        harness.run();
+        harness.press(Key::ArrowLeft);
+        harness.run();
+        harness.type_text("X");
+        assert_eq!(reply(&mut harness), None);
+        assert_eq!(fields.borrow()[0].0, "aXb");
+    }
+
+    #[test]
+    fn tab_moves_between_text_edits() {
+        let (mut harness, fields) = with_text_edits(false);
+        assert_eq!(harness.focused(), Some(fields.borrow()[0].1));
+        harness.press(Key::Tab);
+        harness.run();
+        assert_eq!(harness.focused(), Some(fields.borrow()[1].1));
+    }
+
+    #[test]
+    fn enter_in_a_multiline_text_edit_inserts_a_newline() {
+        let (mut harness, fields) = with_text_edits(true);
+        harness.type_text("a");
+        harness.run();
+        harness.press(Key::Enter);
+        assert_eq!(reply(&mut harness), None);
+        assert_eq!(fields.borrow()[0].0, "a\n");
+    }
+
+    #[test]
+    fn keys_move_between_buttons_and_accept() {
+        let mut dialogs = Dialogs::new();
+        dialogs.animation = None;
+        dialogs.confirm("Delete", "Delete the file?", |reply| reply);
+        let mut harness = Harness::new(dialogs);
+        harness.run();
+        harness.press(Key::ArrowRight);
+        assert_eq!(reply(&mut harness), None);
+        harness.press(Key::Enter);
+        assert_eq!(reply(&mut harness), Some(StandardReply::No));
+    }
+
+    #[test]
+    fn popovers_are_placed_below_the_anchor_and_cancelled_outside() {
+        let mut dialogs = Dialogs::new();
+        dialogs.animation = None;
+        let trash = Rect::from_min_size(egui::pos2(100., 100.), vec2(24., 24.));
+        DialogDetails::confirm("Delete", "Move the file to the trash?")
+            .anchored_to_rect(trash)
+            .show(&mut dialogs);
+        let mut harness = Harness::new(dialogs);
+        harness.run();
+        harness.run();
+
+        assert!(harness.area_rect("Delete").top() > trash.bottom());
+
+        harness.click(egui::pos2(500., 500.));
+        assert_eq!(reply(&mut harness), Some(StandardReply::No));
+    }
+
+    #[test]
+    fn enter_accepts_the_default_button() {
+        let mut dialogs = Dialogs::new();
+        dialogs.animation = None;
+        dialogs.confirm("Delete", "Delete the file?", |reply| reply);
+        let mut harness = Harness::new(dialogs);
+        harness.run();
+        harness.ctx.memory_mut(|m| m.stop_text_input());
+        harness.press(Key::Enter);
+        assert_eq!(reply(&mut harness), Some(StandardReply::Yes));
+    }
 }
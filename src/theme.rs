@@ -0,0 +1,162 @@
+//! The look of dialogs: frame, typography and accent colors.
+
+use egui::{Color32, CornerRadius, FontId, Margin, Shadow, Stroke, Style, TextStyle, Visuals};
+
+use crate::*;
+
+/// The look of dialogs.
+///
+/// Set for all dialogs with [`Dialogs::theme`] and for a dialog with
+/// [`DialogDetails::with_theme`]. Without a theme, dialogs follow the style of the context
+/// with [`DialogTheme::from_style`].
+/// Custom dialogs can read it from [`DialogContext::theme`].
+///
+/// # Example
+/// ```
+/// use egui::{Color32, FontId};
+/// use egui_dialogs::{DialogDetails, DialogSeverity, DialogTheme, Dialogs};
+///
+/// // bigger text and sharp corners for all dialogs
+/// let mut theme = DialogTheme::dark();
+/// theme.body_font = FontId::proportional(18.);
+/// theme.corner_radius = 0.into();
+/// let mut dialogs = Dialogs::new().theme(theme);
+///
+/// // a brighter red for this one, framed in red too
+/// let mut theme = DialogTheme::dark();
+/// theme.accents.error = Color32::RED;
+/// theme.accent_stroke = true;
+/// DialogDetails::error("Error", "Something went wrong")
+///     .with_theme(theme)
+///     .show(&mut dialogs);
+///
+/// assert_eq!(DialogTheme::light().accent(DialogSeverity::Error), DialogTheme::light().accents.error);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DialogTheme {
+    /// The space between the frame and the contents.
+    pub margin: Margin,
+    pub corner_radius: CornerRadius,
+    pub shadow: Shadow,
+    pub fill: Color32,
+    /// The stroke of the frame.
+    pub stroke: Stroke,
+    /// Whether standard dialogs with a severity draw the stroke in their accent color.
+    pub accent_stroke: bool,
+    pub title_font: FontId,
+    pub body_font: FontId,
+    /// The width and height of the icon of standard dialogs.
+    pub icon_size: f32,
+    /// The space between the buttons of standard dialogs.
+    pub button_spacing: f32,
+    pub accents: DialogAccents,
+    /// The icons of standard dialogs.
+    pub icons: DialogIcons,
+}
+
+impl DialogTheme {
+    /// A theme following the visuals and the heading font of the style.
+    pub fn from_style(style: &Style) -> Self {
+        let visuals = &style.visuals;
+        Self {
+            margin: Margin::same(16),
+            corner_radius: visuals.window_corner_radius,
+            shadow: visuals.window_shadow,
+            fill: visuals.window_fill,
+            stroke: visuals.window_stroke,
+            accent_stroke: false,
+            title_font: TextStyle::Heading.resolve(style),
+            body_font: FontId::proportional(16.),
+            icon_size: 48.,
+            button_spacing: style.spacing.item_spacing.x,
+            accents: if visuals.dark_mode {
+                DialogAccents::dark()
+            } else {
+                DialogAccents::light()
+            },
+            icons: DialogIcons::default(),
+        }
+    }
+
+    /// The theme for light backgrounds.
+    pub fn light() -> Self {
+        Self::from_style(&Style {
+            visuals: Visuals::light(),
+            ..Default::default()
+        })
+    }
+
+    /// The theme for dark backgrounds.
+    pub fn dark() -> Self {
+        Self::from_style(&Style {
+            visuals: Visuals::dark(),
+            ..Default::default()
+        })
+    }
+
+    /// The accent color of a severity.
+    pub fn accent(&self, severity: DialogSeverity) -> Color32 {
+        self.accents.get(severity)
+    }
+
+    /// The frame of the dialogs.
+    pub fn frame(&self) -> egui::Frame {
+        egui::Frame::new()
+            .inner_margin(self.margin)
+            .corner_radius(self.corner_radius)
+            .shadow(self.shadow)
+            .fill(self.fill)
+            .stroke(self.stroke)
+    }
+}
+
+impl Default for DialogTheme {
+    fn default() -> Self {
+        Self::from_style(&Style::default())
+    }
+}
+
+/// The accent colors of dialogs for each severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialogAccents {
+    pub info: Color32,
+    pub success: Color32,
+    pub confirm: Color32,
+    pub warning: Color32,
+    pub error: Color32,
+}
+
+impl DialogAccents {
+    /// The colors of the built-in icons, for dark backgrounds.
+    pub fn dark() -> Self {
+        Self {
+            info: Color32::from_rgb(0x58, 0x9c, 0xff),
+            success: Color32::from_rgb(0x67, 0xc2, 0x3a),
+            confirm: Color32::from_rgb(0x84, 0xb9, 0xff),
+            warning: Color32::from_rgb(0xe6, 0xa2, 0x3c),
+            error: Color32::from_rgb(0xf5, 0x6c, 0x6c),
+        }
+    }
+
+    /// Deeper colors for light backgrounds.
+    pub fn light() -> Self {
+        Self {
+            info: Color32::from_rgb(0x2f, 0x7c, 0xe6),
+            success: Color32::from_rgb(0x4e, 0x9a, 0x2a),
+            confirm: Color32::from_rgb(0x4a, 0x8f, 0xe7),
+            warning: Color32::from_rgb(0xc7, 0x85, 0x1a),
+            error: Color32::from_rgb(0xd9, 0x41, 0x3d),
+        }
+    }
+
+    /// The accent color of a severity.
+    pub fn get(&self, severity: DialogSeverity) -> Color32 {
+        match severity {
+            DialogSeverity::Info => self.info,
+            DialogSeverity::Success => self.success,
+            DialogSeverity::Confirm => self.confirm,
+            DialogSeverity::Warning => self.warning,
+            DialogSeverity::Error => self.error,
+        }
+    }
+}
@@ -0,0 +1,301 @@
+//! Transitions moving, scaling and fading dialogs in and out.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use egui::{emath::TSTransform, layers::ShapeIdx, vec2, Color32, Id, LayerId, Pos2, Rect};
+
+/// An easing function mapping the linear progress of an animation to the eased progress.
+pub type Easing = fn(f32) -> f32;
+
+/// How a dialog is drawn at some point of its transition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DialogTransform {
+    /// The opacity of the dialog.
+    pub opacity: f32,
+
+    /// Where the dialog is drawn, relative to where it is laid out.
+    pub transform: TSTransform,
+}
+
+impl DialogTransform {
+    /// The dialog as it is laid out.
+    pub const IDENTITY: Self = Self {
+        opacity: 1.,
+        transform: TSTransform::IDENTITY,
+    };
+}
+
+/// How dialogs appear and disappear.
+///
+/// The transition is driven by a progress going from 0 when the dialog is hidden
+/// to 1 when it is fully shown, and back to 0 when it is closed.
+/// The progress is eased by the animation function of [`Dialogs`](crate::Dialogs).
+///
+/// # Example
+/// ```
+/// use egui::{emath::TSTransform, vec2};
+/// use egui_dialogs::{DialogDetails, DialogTransform, DialogTransition, Dialogs};
+///
+/// // zoom in slowly
+/// let mut dialogs = Dialogs::new().transition(DialogTransition::scale().duration(0.4));
+///
+/// // slide in from the left
+/// let from_left = DialogTransition::custom(|progress, rect, _mask_rect| DialogTransform {
+///     opacity: progress,
+///     transform: TSTransform::from_translation(vec2(-(1. - progress) * rect.right(), 0.)),
+/// });
+/// DialogDetails::info("Hello", "I'm coming from the left")
+///     .with_transition(from_left)
+///     .show(&mut dialogs);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DialogTransition {
+    transform: fn(f32, Rect, Rect) -> DialogTransform,
+
+    /// The duration of the transition in seconds.
+    /// If None, the animation time of the egui style is used.
+    pub duration: Option<f32>,
+}
+
+impl DialogTransition {
+    /// The distance dialogs fall when dropping in.
+    const DROP_DISTANCE: f32 = 16.;
+
+    /// A transition transforming the dialog with a function
+    /// of the progress, the rect of the dialog and the rect of the mask.
+    pub fn custom(transform: fn(f32, Rect, Rect) -> DialogTransform) -> Self {
+        Self {
+            transform,
+            duration: None,
+        }
+    }
+
+    /// Fade the dialog in and out.
+    pub fn fade() -> Self {
+        Self::custom(|progress, _, _| DialogTransform {
+            opacity: progress,
+            transform: TSTransform::IDENTITY,
+        })
+    }
+
+    /// Grow the dialog from its center while fading it in.
+    pub fn scale() -> Self {
+        Self::custom(|progress, rect, _| DialogTransform {
+            opacity: progress,
+            transform: scale_around(rect.center(), egui::lerp(0.8..=1., progress)),
+        })
+    }
+
+    /// Slide the dialog in from above the mask while fading it in.
+    pub fn slide_from_top() -> Self {
+        Self::custom(|progress, rect, mask_rect| DialogTransform {
+            opacity: progress,
+            transform: TSTransform::from_translation(vec2(
+                0.,
+                (1. - progress) * (mask_rect.top() - rect.bottom()),
+            )),
+        })
+    }
+
+    /// Slide the dialog in from below the mask while fading it in.
+    pub fn slide_from_bottom() -> Self {
+        Self::custom(|progress, rect, mask_rect| DialogTransform {
+            opacity: progress,
+            transform: TSTransform::from_translation(vec2(
+                0.,
+                (1. - progress) * (mask_rect.bottom() - rect.top()),
+            )),
+        })
+    }
+
+    /// Slide the dialog in from the left of the mask while fading it in.
+    pub fn slide_from_left() -> Self {
+        Self::custom(|progress, rect, mask_rect| DialogTransform {
+            opacity: progress,
+            transform: TSTransform::from_translation(vec2(
+                (1. - progress) * (mask_rect.left() - rect.right()),
+                0.,
+            )),
+        })
+    }
+
+    /// Slide the dialog in from the right of the mask while fading it in.
+    pub fn slide_from_right() -> Self {
+        Self::custom(|progress, rect, mask_rect| DialogTransform {
+            opacity: progress,
+            transform: TSTransform::from_translation(vec2(
+                (1. - progress) * (mask_rect.right() - rect.left()),
+                0.,
+            )),
+        })
+    }
+
+    /// Drop the dialog in from slightly above and closer to the viewer while fading it in.
+    pub fn drop_in() -> Self {
+        Self::custom(|progress, rect, _| DialogTransform {
+            opacity: progress,
+            transform: TSTransform::from_translation(vec2(
+                0.,
+                -(1. - progress) * Self::DROP_DISTANCE,
+            )) * scale_around(rect.center(), egui::lerp(1.2..=1., progress)),
+        })
+    }
+
+    #[inline]
+    /// Set the duration of the transition in seconds.
+    pub fn duration(mut self, duration: f32) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// How the dialog laid out in `rect` is drawn at the progress.
+    pub fn transform(&self, progress: f32, rect: Rect, mask_rect: Rect) -> DialogTransform {
+        if progress >= 1. {
+            DialogTransform::IDENTITY
+        } else {
+            (self.transform)(progress, rect, mask_rect)
+        }
+    }
+
+    /// The duration of the transition in seconds.
+    pub(crate) fn duration_or_default(&self, ctx: &egui::Context) -> f32 {
+        self.duration.unwrap_or_else(|| ctx.style().animation_time)
+    }
+}
+
+impl Default for DialogTransition {
+    fn default() -> Self {
+        Self::fade()
+    }
+}
+
+/// Scale around a point.
+fn scale_around(center: Pos2, scaling: f32) -> TSTransform {
+    TSTransform::new(center.to_vec2() * (1. - scaling), scaling)
+}
+
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+/// Make all dialogs and their masks appear and disappear instantly,
+/// regardless of their animation functions.
+/// Use it for users sensitive to motion.
+///
+/// # Example
+/// ```
+/// use egui_dialogs::set_reduced_motion;
+///
+/// // e.g. following the settings of the system
+/// let prefers_reduced_motion = true;
+/// set_reduced_motion(prefers_reduced_motion);
+/// # set_reduced_motion(false);
+/// ```
+pub fn set_reduced_motion(reduced: bool) {
+    REDUCED_MOTION.store(reduced, Ordering::Relaxed);
+}
+
+/// Whether motion is reduced with [`set_reduced_motion`].
+pub fn reduced_motion() -> bool {
+    REDUCED_MOTION.load(Ordering::Relaxed)
+}
+
+/// The layers drawn by the current dialog.
+#[derive(Clone, Default)]
+struct DialogLayers(Vec<LayerId>);
+
+fn dialog_layers_id() -> Id {
+    Id::new("egui_dialogs::DialogLayers")
+}
+
+/// Let the transition of the dialog shown in the current update
+/// move and fade the layer.
+///
+/// Windows created with [`dialog_window`](crate::dialog_window) are added automatically.
+/// Use this for other areas drawn by custom dialogs.
+/// All layers are transformed like the first one added, which should be the dialog itself.
+pub fn transition_layer(ctx: &egui::Context, layer_id: LayerId) {
+    ctx.data_mut(|d| {
+        let layers = &mut d
+            .get_temp_mut_or_default::<DialogLayers>(dialog_layers_id())
+            .0;
+        if !layers.contains(&layer_id) {
+            layers.push(layer_id);
+        }
+    });
+}
+
+/// Take the layers added with [`transition_layer`].
+pub(crate) fn take_transition_layers(ctx: &egui::Context) -> Vec<LayerId> {
+    ctx.data_mut(|d| d.remove_temp::<DialogLayers>(dialog_layers_id()))
+        .unwrap_or_default()
+        .0
+}
+
+/// Transform the shapes painted to the layers in this pass
+/// like the first layer, which is the dialog itself.
+pub(crate) fn apply_transition(
+    ctx: &egui::Context,
+    layers: &[LayerId],
+    transition: &DialogTransition,
+    progress: f32,
+    mask_rect: Rect,
+) {
+    let Some(rect) = layers
+        .first()
+        .and_then(|layer_id| ctx.memory(|m| m.area_rect(layer_id.id)))
+    else {
+        return;
+    };
+
+    let DialogTransform { opacity, transform } = transition.transform(progress, rect, mask_rect);
+    if opacity >= 1. && transform == TSTransform::IDENTITY {
+        return;
+    }
+
+    let opacity = opacity.clamp(0., 1.);
+    for &layer_id in layers {
+        ctx.graphics_mut(|g| {
+            let Some(shapes) = g.get_mut(layer_id) else {
+                return;
+            };
+            if opacity < 1. {
+                for i in 0..shapes.next_idx().0 {
+                    shapes.mutate_shape(ShapeIdx(i), |shape| {
+                        egui::epaint::shape_transform::adjust_colors(
+                            &mut shape.shape,
+                            move |color| {
+                                if *color != Color32::PLACEHOLDER {
+                                    *color = color.gamma_multiply(opacity);
+                                }
+                            },
+                        );
+                    });
+                }
+            }
+            shapes.transform(transform);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::Key;
+
+    use super::*;
+    use crate::{harness::Harness, DialogDetails, Dialogs};
+
+    #[test]
+    fn reduced_motion_closes_dialogs_instantly() {
+        let mut dialogs = Dialogs::new();
+        DialogDetails::info("Hello", "Nothing moves here")
+            .with_animation(Some(egui::emath::easing::cubic_out))
+            .show(&mut dialogs);
+        let mut harness = Harness::new(dialogs);
+        set_reduced_motion(true);
+
+        harness.run();
+        harness.press(Key::Enter);
+        assert!(harness.run().is_some_and(|response| response.is_reply()));
+        // the dialog is gone without fading out
+        assert!(harness.run().is_none());
+    }
+}
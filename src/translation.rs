@@ -0,0 +1,515 @@
+//! Translate the texts built into the dialogs.
+
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::*;
+
+// the texts of a reply by locale
+// every reply is translated into the same locales
+type StandardReplyTranslation = &'static [(&'static str, &'static str)];
+
+const STANDARD_OK_REPLY: StandardReplyTranslation = &[
+    ("en-US", "OK"),
+    ("zh-CN", "确定"),
+    ("zh-TW", "確定"),
+    ("es", "OK"),
+    ("fr", "D'accord"),
+    ("it", "OK"),
+    ("ja", "はい"),
+    ("pt-BR", "OK"),
+    ("ru", "Хорошо"),
+    ("tr", "Tamam"),
+    ("ar", "حسناً"),
+    ("he", "אישור"),
+    ("de", "OK"),
+    ("ko", "확인"),
+    ("pl", "OK"),
+    ("nl", "OK"),
+    ("sv", "OK"),
+    ("uk", "Гаразд"),
+    ("vi", "OK"),
+    ("id", "OK"),
+    ("cs", "OK"),
+    ("hi", "ठीक है"),
+    ("da", "OK"),
+    ("fi", "OK"),
+    ("nb", "OK"),
+    ("el", "OK"),
+    ("hu", "OK"),
+    ("ro", "OK"),
+    ("th", "ตกลง"),
+    ("fa", "تأیید"),
+    ("bg", "OK"),
+    ("sk", "OK"),
+    ("ca", "D'acord"),
+    ("hr", "U redu"),
+];
+
+const STANDARD_CANCEL_REPLY: StandardReplyTranslation = &[
+    ("en-US", "Cancel"),
+    ("zh-CN", "取消"),
+    ("zh-TW", "取消"),
+    ("es", "Cancelar"),
+    ("fr", "Annuler"),
+    ("it", "Annulla"),
+    ("ja", "キャンセル"),
+    ("pt-BR", "Cancelar"),
+    ("ru", "Отмена"),
+    ("tr", "İptal"),
+    ("ar", "إلغاء"),
+    ("he", "ביטול"),
+    ("de", "Abbrechen"),
+    ("ko", "취소"),
+    ("pl", "Anuluj"),
+    ("nl", "Annuleren"),
+    ("sv", "Avbryt"),
+    ("uk", "Скасувати"),
+    ("vi", "Hủy"),
+    ("id", "Batal"),
+    ("cs", "Zrušit"),
+    ("hi", "रद्द करें"),
+    ("da", "Annuller"),
+    ("fi", "Peruuta"),
+    ("nb", "Avbryt"),
+    ("el", "Άκυρο"),
+    ("hu", "Mégse"),
+    ("ro", "Anulează"),
+    ("th", "ยกเลิก"),
+    ("fa", "لغو"),
+    ("bg", "Отказ"),
+    ("sk", "Zrušiť"),
+    ("ca", "Cancel·la"),
+    ("hr", "Odustani"),
+];
+
+const STANDARD_YES_REPLY: StandardReplyTranslation = &[
+    ("en-US", "Yes"),
+    ("zh-CN", "是"),
+    ("zh-TW", "是"),
+    ("es", "Sí"),
+    ("fr", "Oui"),
+    ("it", "Sì"),
+    ("ja", "はい"),
+    ("pt-BR", "Sim"),
+    ("ru", "Да"),
+    ("tr", "Evet"),
+    ("ar", "نعم"),
+    ("he", "כן"),
+    ("de", "Ja"),
+    ("ko", "예"),
+    ("pl", "Tak"),
+    ("nl", "Ja"),
+    ("sv", "Ja"),
+    ("uk", "Так"),
+    ("vi", "Có"),
+    ("id", "Ya"),
+    ("cs", "Ano"),
+    ("hi", "हाँ"),
+    ("da", "Ja"),
+    ("fi", "Kyllä"),
+    ("nb", "Ja"),
+    ("el", "Ναι"),
+    ("hu", "Igen"),
+    ("ro", "Da"),
+    ("th", "ใช่"),
+    ("fa", "بله"),
+    ("bg", "Да"),
+    ("sk", "Áno"),
+    ("ca", "Sí"),
+    ("hr", "Da"),
+];
+
+const STANDARD_NO_REPLY: StandardReplyTranslation = &[
+    ("en-US", "No"),
+    ("zh-CN", "否"),
+    ("zh-TW", "否"),
+    ("es", "No"),
+    ("fr", "Non"),
+    ("it", "No"),
+    ("ja", "いいえ"),
+    ("pt-BR", "Não"),
+    ("ru", "Нет"),
+    ("tr", "Hayır"),
+    ("ar", "لا"),
+    ("he", "לא"),
+    ("de", "Nein"),
+    ("ko", "아니요"),
+    ("pl", "Nie"),
+    ("nl", "Nee"),
+    ("sv", "Nej"),
+    ("uk", "Ні"),
+    ("vi", "Không"),
+    ("id", "Tidak"),
+    ("cs", "Ne"),
+    ("hi", "नहीं"),
+    ("da", "Nej"),
+    ("fi", "Ei"),
+    ("nb", "Nei"),
+    ("el", "Όχι"),
+    ("hu", "Nem"),
+    ("ro", "Nu"),
+    ("th", "ไม่ใช่"),
+    ("fa", "خیر"),
+    ("bg", "Не"),
+    ("sk", "Nie"),
+    ("ca", "No"),
+    ("hr", "Ne"),
+];
+
+// the buttons of wizards, which share the cancel text of the replies
+const WIZARD_BACK_BUTTON: StandardReplyTranslation = &[
+    ("en-US", "Back"),
+    ("zh-CN", "上一步"),
+    ("zh-TW", "上一步"),
+    ("es", "Atrás"),
+    ("fr", "Précédent"),
+    ("it", "Indietro"),
+    ("ja", "戻る"),
+    ("pt-BR", "Voltar"),
+    ("ru", "Назад"),
+    ("tr", "Geri"),
+    ("ar", "السابق"),
+    ("he", "הקודם"),
+    ("de", "Zurück"),
+    ("ko", "이전"),
+    ("pl", "Wstecz"),
+    ("nl", "Vorige"),
+    ("sv", "Tillbaka"),
+    ("uk", "Назад"),
+    ("vi", "Quay lại"),
+    ("id", "Kembali"),
+    ("cs", "Zpět"),
+    ("hi", "पीछे"),
+    ("da", "Tilbage"),
+    ("fi", "Edellinen"),
+    ("nb", "Tilbake"),
+    ("el", "Πίσω"),
+    ("hu", "Vissza"),
+    ("ro", "Înapoi"),
+    ("th", "ย้อนกลับ"),
+    ("fa", "قبلی"),
+    ("bg", "Назад"),
+    ("sk", "Späť"),
+    ("ca", "Enrere"),
+    ("hr", "Natrag"),
+];
+
+const WIZARD_NEXT_BUTTON: StandardReplyTranslation = &[
+    ("en-US", "Next"),
+    ("zh-CN", "下一步"),
+    ("zh-TW", "下一步"),
+    ("es", "Siguiente"),
+    ("fr", "Suivant"),
+    ("it", "Avanti"),
+    ("ja", "次へ"),
+    ("pt-BR", "Avançar"),
+    ("ru", "Далее"),
+    ("tr", "İleri"),
+    ("ar", "التالي"),
+    ("he", "הבא"),
+    ("de", "Weiter"),
+    ("ko", "다음"),
+    ("pl", "Dalej"),
+    ("nl", "Volgende"),
+    ("sv", "Nästa"),
+    ("uk", "Далі"),
+    ("vi", "Tiếp theo"),
+    ("id", "Berikutnya"),
+    ("cs", "Další"),
+    ("hi", "आगे"),
+    ("da", "Næste"),
+    ("fi", "Seuraava"),
+    ("nb", "Neste"),
+    ("el", "Επόμενο"),
+    ("hu", "Tovább"),
+    ("ro", "Următorul"),
+    ("th", "ถัดไป"),
+    ("fa", "بعدی"),
+    ("bg", "Напред"),
+    ("sk", "Ďalej"),
+    ("ca", "Següent"),
+    ("hr", "Dalje"),
+];
+
+const WIZARD_FINISH_BUTTON: StandardReplyTranslation = &[
+    ("en-US", "Finish"),
+    ("zh-CN", "完成"),
+    ("zh-TW", "完成"),
+    ("es", "Finalizar"),
+    ("fr", "Terminer"),
+    ("it", "Fine"),
+    ("ja", "完了"),
+    ("pt-BR", "Concluir"),
+    ("ru", "Готово"),
+    ("tr", "Bitir"),
+    ("ar", "إنهاء"),
+    ("he", "סיום"),
+    ("de", "Fertigstellen"),
+    ("ko", "완료"),
+    ("pl", "Zakończ"),
+    ("nl", "Voltooien"),
+    ("sv", "Slutför"),
+    ("uk", "Готово"),
+    ("vi", "Hoàn tất"),
+    ("id", "Selesai"),
+    ("cs", "Dokončit"),
+    ("hi", "समाप्त"),
+    ("da", "Udfør"),
+    ("fi", "Valmis"),
+    ("nb", "Fullfør"),
+    ("el", "Τέλος"),
+    ("hu", "Befejezés"),
+    ("ro", "Finalizare"),
+    ("th", "เสร็จสิ้น"),
+    ("fa", "پایان"),
+    ("bg", "Готово"),
+    ("sk", "Dokončiť"),
+    ("ca", "Finalitza"),
+    ("hr", "Završi"),
+];
+
+// the error of required form fields left empty
+const FORM_REQUIRED_ERROR: StandardReplyTranslation = &[
+    ("en-US", "Required"),
+    ("zh-CN", "必填"),
+    ("zh-TW", "必填"),
+    ("es", "Obligatorio"),
+    ("fr", "Obligatoire"),
+    ("it", "Obbligatorio"),
+    ("ja", "必須"),
+    ("pt-BR", "Obrigatório"),
+    ("ru", "Обязательное поле"),
+    ("tr", "Zorunlu"),
+    ("ar", "مطلوب"),
+    ("he", "שדה חובה"),
+    ("de", "Erforderlich"),
+    ("ko", "필수"),
+    ("pl", "Wymagane"),
+    ("nl", "Verplicht"),
+    ("sv", "Obligatoriskt"),
+    ("uk", "Обовʼязкове поле"),
+    ("vi", "Bắt buộc"),
+    ("id", "Wajib diisi"),
+    ("cs", "Povinné"),
+    ("hi", "आवश्यक"),
+    ("da", "Påkrævet"),
+    ("fi", "Pakollinen"),
+    ("nb", "Påkrevd"),
+    ("el", "Υποχρεωτικό"),
+    ("hu", "Kötelező"),
+    ("ro", "Obligatoriu"),
+    ("th", "จำเป็น"),
+    ("fa", "الزامی"),
+    ("bg", "Задължително"),
+    ("sk", "Povinné"),
+    ("ca", "Obligatori"),
+    ("hr", "Obavezno"),
+];
+
+// the accessible label of the close button of dialog windows
+const CLOSE_BUTTON_LABEL: StandardReplyTranslation = &[
+    ("en-US", "Close window"),
+    ("zh-CN", "关闭窗口"),
+    ("zh-TW", "關閉視窗"),
+    ("es", "Cerrar ventana"),
+    ("fr", "Fermer la fenêtre"),
+    ("it", "Chiudi finestra"),
+    ("ja", "ウィンドウを閉じる"),
+    ("pt-BR", "Fechar janela"),
+    ("ru", "Закрыть окно"),
+    ("tr", "Pencereyi kapat"),
+    ("ar", "إغلاق النافذة"),
+    ("he", "סגירת החלון"),
+    ("de", "Fenster schließen"),
+    ("ko", "창 닫기"),
+    ("pl", "Zamknij okno"),
+    ("nl", "Venster sluiten"),
+    ("sv", "Stäng fönstret"),
+    ("uk", "Закрити вікно"),
+    ("vi", "Đóng cửa sổ"),
+    ("id", "Tutup jendela"),
+    ("cs", "Zavřít okno"),
+    ("hi", "विंडो बंद करें"),
+    ("da", "Luk vindue"),
+    ("fi", "Sulje ikkuna"),
+    ("nb", "Lukk vinduet"),
+    ("el", "Κλείσιμο παραθύρου"),
+    ("hu", "Ablak bezárása"),
+    ("ro", "Închide fereastra"),
+    ("th", "ปิดหน้าต่าง"),
+    ("fa", "بستن پنجره"),
+    ("bg", "Затваряне на прозореца"),
+    ("sk", "Zavrieť okno"),
+    ("ca", "Tanca la finestra"),
+    ("hr", "Zatvori prozor"),
+];
+
+// the built-in translations by key
+const STANDARD_TRANSLATIONS: [(&str, StandardReplyTranslation); 9] = [
+    ("ok", STANDARD_OK_REPLY),
+    ("cancel", STANDARD_CANCEL_REPLY),
+    ("yes", STANDARD_YES_REPLY),
+    ("no", STANDARD_NO_REPLY),
+    ("back", WIZARD_BACK_BUTTON),
+    ("next", WIZARD_NEXT_BUTTON),
+    ("finish", WIZARD_FINISH_BUTTON),
+    ("required", FORM_REQUIRED_ERROR),
+    ("close", CLOSE_BUTTON_LABEL),
+];
+
+/// The built-in translations as `(key, locale, text)`.
+#[cfg_attr(not(feature = "fluent"), allow(dead_code))]
+pub(crate) fn standard_texts() -> impl Iterator<Item = (&'static str, &'static str, &'static str)> {
+    STANDARD_TRANSLATIONS.iter().flat_map(|(key, source)| {
+        source
+            .iter()
+            .map(move |(locale, text)| (*key, *locale, *text))
+    })
+}
+
+/// Translates the texts built into the dialogs, such as the standard buttons.
+/// Texts are identified by keys, see [`StandardReply::key`] and [`WizardButton::key`],
+/// `required` for the error of empty form fields and `close` for the close button of windows.
+///
+/// Closures taking a key are translators as well.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+///
+/// use egui_dialogs::{set_translator, StandardReply};
+///
+/// set_translator(Some(Arc::new(|key: &str| match key {
+///     "ok" => Some("Alright".to_owned()),
+///     // use the built-in translation for the others
+///     _ => None,
+/// })));
+///
+/// assert_eq!(StandardReply::Ok.localize(), "Alright");
+/// # set_translator(None);
+/// ```
+pub trait Translator: Send + Sync {
+    /// Translate the text with the key.
+    /// Returns None to use the built-in translation.
+    fn translate(&self, key: &str) -> Option<String>;
+}
+
+impl<F> Translator for F
+where
+    F: Fn(&str) -> Option<String> + Send + Sync,
+{
+    fn translate(&self, key: &str) -> Option<String> {
+        (self)(key)
+    }
+}
+
+/// The translator backed by the built-in translation tables.
+/// Follows the locale set with [`set_locale`], or the system locales.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StandardTranslator;
+
+impl StandardTranslator {
+    /// The locales of the built-in translations.
+    pub fn locales() -> Vec<&'static str> {
+        let mut locales = vec![];
+        for (_, source) in STANDARD_TRANSLATIONS {
+            for (locale, _) in source {
+                if !locales.contains(locale) {
+                    locales.push(*locale);
+                }
+            }
+        }
+        locales
+    }
+
+    /// The built-in text with the key in exactly the locale, if there is one.
+    ///
+    /// # Example
+    /// ```
+    /// use egui_dialogs::StandardTranslator;
+    ///
+    /// assert_eq!(StandardTranslator::text("cancel", "de"), Some("Abbrechen"));
+    /// assert_eq!(StandardTranslator::text("cancel", "de-DE"), None);
+    /// ```
+    pub fn text(key: &str, locale: &str) -> Option<&'static str> {
+        let (_, source) = STANDARD_TRANSLATIONS.iter().find(|(k, _)| *k == key)?;
+        source
+            .iter()
+            .find(|(locale_key, _)| *locale_key == locale)
+            .map(|(_, text)| *text)
+    }
+}
+
+impl Translator for StandardTranslator {
+    fn translate(&self, key: &str) -> Option<String> {
+        Self::text(key, active_locale())
+            .or_else(|| Self::text(key, FALLBACK_LOCALE))
+            .map(str::to_owned)
+    }
+}
+
+static TRANSLATOR: RwLock<Option<Arc<dyn Translator>>> = RwLock::new(None);
+
+/// Set the translator of the texts built into the dialogs.
+/// Use None to go back to the [`StandardTranslator`].
+///
+/// Texts are translated when the dialogs are built.
+pub fn set_translator(translator: Option<Arc<dyn Translator>>) {
+    *TRANSLATOR.write().unwrap_or_else(PoisonError::into_inner) = translator;
+    reset_active_locale();
+}
+
+/// Translate a built-in text with the translator set by [`set_translator`],
+/// falling back to the [`StandardTranslator`] and then to the key itself.
+pub fn translate(key: &str) -> String {
+    let translator = TRANSLATOR
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+
+    translator
+        .and_then(|translator| translator.translate(key))
+        .or_else(|| StandardTranslator.translate(key))
+        .unwrap_or_else(|| key.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_text_is_translated_into_every_locale() {
+        let locales = StandardTranslator::locales();
+        assert!(locales.len() >= 30);
+        for (key, source) in STANDARD_TRANSLATIONS {
+            // the tables list the locales in the same order, once each
+            let table_locales: Vec<_> = source.iter().map(|(locale, _)| *locale).collect();
+            assert_eq!(table_locales, locales, "{key}");
+            for (locale, text) in source {
+                assert!(!text.trim().is_empty(), "{key} in {locale}");
+            }
+        }
+    }
+
+    #[test]
+    fn every_button_has_a_text() {
+        let replies = [
+            StandardReply::Ok,
+            StandardReply::Cancel,
+            StandardReply::Yes,
+            StandardReply::No,
+        ];
+        let buttons = [WizardButton::Back, WizardButton::Next, WizardButton::Finish];
+        let keys = replies
+            .iter()
+            .map(|reply| reply.key())
+            .chain(buttons.iter().map(|button| button.key()))
+            .chain(["required", "close"]);
+        for key in keys {
+            assert!(
+                StandardTranslator::text(key, FALLBACK_LOCALE).is_some(),
+                "{key}"
+            );
+        }
+    }
+}
@@ -0,0 +1,547 @@
+//! Dialogs leading the user through several pages.
+
+use std::sync::Arc;
+
+use egui::{
+    vec2, Align, Button, Id, Label, Layout, RichText, ScrollArea, Sense, Stroke, Ui, Vec2,
+    WidgetText,
+};
+
+use crate::*;
+
+/// The spacing around the separator above the buttons.
+const BUTTONS_SEPARATOR_SPACING: f32 = 6.;
+
+/// The buttons of a [`WizardDialog`].
+/// Can be translated to the current locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WizardButton {
+    Back,
+    Next,
+    Finish,
+    Cancel,
+}
+
+impl WizardButton {
+    /// Translate the button with the current translator.
+    /// See [`set_translator`].
+    pub fn localize(self) -> String {
+        translate(self.key())
+    }
+
+    /// The key of the button text for translators.
+    /// Cancel shares its text with [`StandardReply::Cancel`].
+    pub fn key(self) -> &'static str {
+        match self {
+            WizardButton::Back => "back",
+            WizardButton::Next => "next",
+            WizardButton::Finish => "finish",
+            WizardButton::Cancel => "cancel",
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            WizardButton::Back => 0,
+            WizardButton::Next => 1,
+            WizardButton::Finish => 2,
+            WizardButton::Cancel => 3,
+        }
+    }
+}
+
+impl std::fmt::Display for WizardButton {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.localize())
+    }
+}
+
+/// A function showing the widgets of a wizard page, editing the state of the wizard.
+pub type WizardPageContents<'a, State> = dyn FnMut(&mut Ui, &mut State) + 'a;
+
+/// A function telling whether the state of the wizard allows to leave a page.
+pub type WizardValidation<'a, State> = dyn Fn(&State) -> bool + 'a;
+
+/// A page of a [`WizardDialog`].
+pub struct WizardPage<'a, State> {
+    pub title: WidgetText,
+    pub contents: Box<WizardPageContents<'a, State>>,
+    /// If there is one, the next page or finishing is only allowed when it returns true.
+    pub validation: Option<Box<WizardValidation<'a, State>>>,
+}
+
+impl<'a, State> WizardPage<'a, State> {
+    pub fn new(
+        title: impl Into<WidgetText>,
+        contents: impl FnMut(&mut Ui, &mut State) + 'a,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            contents: Box::new(contents),
+            validation: None,
+        }
+    }
+
+    /// Only allow to go to the next page, or to finish, when the state is valid
+    #[inline]
+    pub fn validate(mut self, validation: impl Fn(&State) -> bool + 'a) -> Self {
+        self.validation = Some(Box::new(validation));
+        self
+    }
+
+    /// Whether the state allows to leave the page.
+    pub fn is_valid(&self, state: &State) -> bool {
+        self.validation
+            .as_ref()
+            .is_none_or(|validation| validation(state))
+    }
+}
+
+/// A dialog leading the user through pages, which all edit the same state.
+///
+/// The user goes from page to page with the Back and Next buttons,
+/// or the accept key, and a step indicator shows where they are.
+/// Replies with the state when the user finishes on the last page,
+/// and with None when they cancel.
+///
+/// # Example
+/// ```
+/// use egui_dialogs::{DialogDetails, WizardDialog, WizardPage};
+///
+/// #[derive(Default)]
+/// struct Export {
+///     path: String,
+///     compress: bool,
+/// }
+///
+/// let wizard = WizardDialog::new("Export", Export::default())
+///     .push_page(
+///         WizardPage::new("Destination", |ui, export: &mut Export| {
+///             ui.label("Where should the files go?");
+///             ui.text_edit_singleline(&mut export.path);
+///         })
+///         // Next is disabled until there is a path
+///         .validate(|export| !export.path.is_empty()),
+///     )
+///     .push_page(WizardPage::new("Options", |ui, export: &mut Export| {
+///         ui.checkbox(&mut export.compress, "Compress the files");
+///     }));
+///
+/// # let mut dialogs = egui_dialogs::Dialogs::new();
+/// DialogDetails::new(wizard)
+///     .on_reply(|export| {
+///         if let Some(export) = export {
+///             println!("exporting to {}", export.path);
+///         }
+///     })
+///     .show(&mut dialogs);
+/// ```
+pub struct WizardDialog<'a, State> {
+    pub title: WidgetText,
+    pub pages: Vec<WizardPage<'a, State>>,
+    /// The width of the pages, unless the dialog is resizable or fills the width.
+    pub width: f32,
+    /// The texts of the buttons, translated when the dialog is built.
+    button_texts: [WidgetText; 4],
+    /// The state, until it is replied.
+    state: Option<State>,
+    current: usize,
+    // kept while the dialog transitions out without its state
+    page_size: Vec2,
+}
+
+impl<'a, State> WizardDialog<'a, State> {
+    pub fn new(title: impl Into<WidgetText>, state: State) -> Self {
+        Self {
+            title: title.into(),
+            pages: vec![],
+            width: 420.,
+            button_texts: [
+                WizardButton::Back,
+                WizardButton::Next,
+                WizardButton::Finish,
+                WizardButton::Cancel,
+            ]
+            .map(|button| button.localize().into()),
+            state: Some(state),
+            current: 0,
+            page_size: Vec2::ZERO,
+        }
+    }
+
+    /// Set the dialog title
+    #[inline]
+    pub fn title(mut self, title: impl Into<WidgetText>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Set the dialog pages
+    #[inline]
+    pub fn pages(mut self, pages: Vec<WizardPage<'a, State>>) -> Self {
+        self.pages = pages;
+        self
+    }
+
+    /// Add a page to the dialog
+    #[inline]
+    pub fn push_page(mut self, page: WizardPage<'a, State>) -> Self {
+        self.pages.push(page);
+        self
+    }
+
+    /// Set the width of the pages
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the text of a button instead of its translation, e.g. "Export" to finish
+    #[inline]
+    pub fn button_text(mut self, button: WizardButton, text: impl Into<WidgetText>) -> Self {
+        self.button_texts[button.index()] = text.into();
+        self
+    }
+
+    /// The index of the page shown.
+    #[inline]
+    pub fn current_page(&self) -> usize {
+        self.current
+    }
+
+    /// The state, or None once it has been replied.
+    #[inline]
+    pub fn state(&self) -> Option<&State> {
+        self.state.as_ref()
+    }
+
+    /// Whether the current page allows to go on.
+    fn is_valid(&self) -> bool {
+        match (&self.state, self.pages.get(self.current)) {
+            (Some(state), Some(page)) => page.is_valid(state),
+            _ => false,
+        }
+    }
+
+    /// Show the steps of the wizard and the title of the current page.
+    /// Returns the id of the title.
+    fn show_steps(&self, ui: &mut Ui, dctx: &DialogContext) -> Option<Id> {
+        const RADIUS: f32 = 4.;
+
+        let layout = if dctx.direction.is_rtl() {
+            Layout::right_to_left(Align::Center)
+        } else {
+            Layout::left_to_right(Align::Center)
+        };
+        let row = vec2(ui.available_width(), ui.spacing().interact_size.y);
+        ui.allocate_ui_with_layout(row, layout, |ui| {
+            ui.spacing_mut().item_spacing.x = RADIUS * 1.5;
+            let done = ui.visuals().selection.bg_fill;
+            let to_do = ui.visuals().weak_text_color();
+            for index in 0..self.pages.len() {
+                let (rect, _) = ui.allocate_exact_size(Vec2::splat(RADIUS * 2.), Sense::hover());
+                if index <= self.current {
+                    ui.painter().circle_filled(rect.center(), RADIUS, done);
+                } else {
+                    ui.painter()
+                        .circle_stroke(rect.center(), RADIUS - 0.5, Stroke::new(1., to_do));
+                }
+            }
+
+            let page = self.pages.get(self.current)?;
+            ui.add_space(RADIUS);
+            let title = RichText::new(page.title.text()).strong();
+            Some(ui.add(Label::new(title).truncate()).id)
+        })
+        .inner
+    }
+
+    /// Show the Back, Next or Finish, and Cancel buttons.
+    /// Returns the button clicked if there is one.
+    fn show_buttons(&self, ui: &mut Ui, dctx: &DialogContext) -> Option<WizardButton> {
+        let mut clicked = None;
+        let is_last = self.current + 1 >= self.pages.len();
+
+        // Next and Back at the end, Cancel at the start
+        let (start, end) = if dctx.direction.is_rtl() {
+            (
+                Layout::right_to_left(Align::Center),
+                Layout::left_to_right(Align::Center),
+            )
+        } else {
+            (
+                Layout::left_to_right(Align::Center),
+                Layout::right_to_left(Align::Center),
+            )
+        };
+        let row = vec2(ui.available_width(), ui.spacing().interact_size.y);
+        ui.allocate_ui_with_layout(row, end, |ui| {
+            ui.spacing_mut().item_spacing.x = dctx.theme.button_spacing;
+            let mut button = |ui: &mut Ui, button: WizardButton, enabled: bool| {
+                let text = self.button_texts[button.index()].clone();
+                if ui.add_enabled(enabled, Button::new(text)).clicked() {
+                    clicked = Some(button);
+                }
+            };
+
+            let forward = if is_last {
+                WizardButton::Finish
+            } else {
+                WizardButton::Next
+            };
+            button(ui, forward, self.is_valid());
+            button(ui, WizardButton::Back, self.current > 0);
+            ui.with_layout(start, |ui| button(ui, WizardButton::Cancel, true));
+        });
+
+        clicked
+    }
+}
+
+impl<'a, State> Dialog<Option<State>> for WizardDialog<'a, State> {
+    fn show(&mut self, ctx: &egui::Context, dctx: &DialogContext) -> Option<Option<State>> {
+        let mut open = true;
+        let mut clicked = None;
+        let mut title_id = None;
+
+        self.current = self.current.min(self.pages.len().saturating_sub(1));
+        let is_last = self.current + 1 >= self.pages.len();
+
+        // the accept key goes on, unless a widget like a text edit takes it
+        if !dctx.already_closed
+            && self.is_valid()
+            && !ctx.wants_keyboard_input()
+            && DialogKeys::consume(ctx, &dctx.keys.accept)
+        {
+            clicked = Some(if is_last {
+                WizardButton::Finish
+            } else {
+                WizardButton::Next
+            });
+        }
+
+        closable_dialog_window(ctx, dctx, self.title.clone(), &mut open).show(ctx, |ui| {
+            ui.set_style(Arc::clone(&dctx.style));
+            ui.style_mut().override_font_id = Some(dctx.theme.body_font.clone());
+
+            // windows grow with right-to-left layouts, so the width is fixed
+            let width = if dctx.resizable || dctx.presentation.fills_width() {
+                ui.available_width()
+            } else {
+                let frame_width = dctx.theme.margin.sum().x + dctx.theme.stroke.width * 2.;
+                let max_width = dctx.max_size.unwrap_or(dctx.mask_rect.size()).x - frame_width;
+                self.width.min(max_width)
+            };
+            ui.set_width(width);
+            if dctx.presentation.fills_height() {
+                // windows shrink to their contents vertically
+                ui.set_min_height(ui.max_rect().height());
+            }
+
+            title_id = self.show_steps(ui, dctx);
+            ui.separator();
+
+            // room for the buttons below
+            let buttons_height = ui.spacing().interact_size.y.max(
+                ui.text_style_height(&egui::TextStyle::Button) + ui.spacing().button_padding.y * 2.,
+            ) + ui.spacing().item_spacing.y * 2.
+                + BUTTONS_SEPARATOR_SPACING;
+            let halign = if dctx.direction.is_rtl() {
+                Align::RIGHT
+            } else {
+                Align::LEFT
+            };
+            ScrollArea::vertical()
+                .id_salt(self.current)
+                .auto_shrink([false, true])
+                .max_height(ui.available_height() - buttons_height)
+                .show(ui, |ui| {
+                    ui.with_layout(Layout::top_down(halign), |ui| {
+                        match (&mut self.state, self.pages.get_mut(self.current)) {
+                            (Some(state), Some(page)) => (page.contents)(ui, state),
+                            // the state has been replied, keep the size of the page
+                            _ => {
+                                ui.allocate_space(self.page_size);
+                            }
+                        }
+                        self.page_size = ui.min_rect().size();
+                    });
+                });
+
+            ui.add(egui::Separator::default().spacing(BUTTONS_SEPARATOR_SPACING));
+            clicked = clicked.or(self.show_buttons(ui, dctx));
+        });
+
+        #[cfg(feature = "accesskit")]
+        if let Some(title_id) = title_id {
+            describe_dialog(ctx, None, title_id);
+        }
+        #[cfg(not(feature = "accesskit"))]
+        let _ = title_id;
+
+        if dctx.already_closed {
+            return None;
+        }
+
+        match clicked {
+            Some(WizardButton::Back) => {
+                self.current = self.current.saturating_sub(1);
+                None
+            }
+            Some(WizardButton::Next) => {
+                self.current += 1;
+                None
+            }
+            Some(WizardButton::Finish) => Some(self.state.take()),
+            Some(WizardButton::Cancel) => Some(None),
+            None if !open => Some(None),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use egui::{output::OutputEvent, Key};
+
+    use super::*;
+    use crate::harness::Harness;
+
+    /// The name typed on the first page and the page shown in the last frame.
+    #[derive(Default)]
+    struct Inputs {
+        name: String,
+        page: usize,
+    }
+
+    /// A wizard asking for a name on its first page, required to go on,
+    /// and showing it on the second page if `two_pages` is set.
+    fn open(two_pages: bool) -> (Harness<'static>, Rc<RefCell<Inputs>>) {
+        let inputs = Rc::new(RefCell::new(Inputs::default()));
+        let name_page = WizardPage::new("Name", {
+            let inputs = Rc::clone(&inputs);
+            move |_: &mut Ui, name: &mut String| {
+                let mut inputs = inputs.borrow_mut();
+                // typed in the test rather than in a text edit taking the keys
+                if !inputs.name.is_empty() {
+                    *name = std::mem::take(&mut inputs.name);
+                }
+                inputs.page = 0;
+            }
+        })
+        .validate(|name: &String| !name.is_empty());
+        let mut wizard = WizardDialog::new("Welcome", String::new()).push_page(name_page);
+        if two_pages {
+            wizard = wizard.push_page(WizardPage::new("Done", {
+                let inputs = Rc::clone(&inputs);
+                move |_: &mut Ui, _: &mut String| inputs.borrow_mut().page = 1
+            }));
+        }
+
+        let mut dialogs = Dialogs::new().animated(false);
+        DialogDetails::new(wizard).show(&mut dialogs);
+        let mut harness = Harness::new(dialogs);
+        set_locale(Some("en-US".into()));
+        harness.run();
+        (harness, inputs)
+    }
+
+    fn reply(harness: &mut Harness) -> Option<Option<String>> {
+        harness.run().and_then(|response| response.reply().ok())
+    }
+
+    /// Move the focus with Tab to the button with the label and press it.
+    fn press_button(harness: &mut Harness, label: &str) -> Option<Option<String>> {
+        for _ in 0..10 {
+            harness.press(Key::Tab);
+            let (_, output) = harness.run_with(|_| {});
+            let focused = output.platform_output.events.iter().any(|event| {
+                matches!(event, OutputEvent::FocusGained(info) if info.label.as_deref() == Some(label))
+            });
+            if focused {
+                harness.press(Key::Enter);
+                return reply(harness);
+            }
+        }
+        panic!("no button {label}");
+    }
+
+    #[test]
+    fn validation_gates_next_and_the_accept_key() {
+        let (mut harness, inputs) = open(true);
+        harness.press(Key::Enter);
+        assert_eq!(reply(&mut harness), None);
+        harness.run();
+        assert_eq!(inputs.borrow().page, 0);
+
+        inputs.borrow_mut().name = "Ada".to_owned();
+        harness.run();
+        harness.press(Key::Enter);
+        assert_eq!(reply(&mut harness), None);
+        harness.run();
+        assert_eq!(inputs.borrow().page, 1);
+    }
+
+    #[test]
+    fn validation_gates_finish() {
+        let (mut harness, inputs) = open(false);
+        harness.press(Key::Enter);
+        assert_eq!(reply(&mut harness), None);
+
+        inputs.borrow_mut().name = "Ada".to_owned();
+        harness.run();
+        assert_eq!(
+            press_button(&mut harness, &WizardButton::Finish.localize()),
+            Some(Some("Ada".to_owned()))
+        );
+    }
+
+    #[test]
+    fn back_keeps_the_state() {
+        let (mut harness, inputs) = open(true);
+        inputs.borrow_mut().name = "Ada".to_owned();
+        harness.run();
+        harness.press(Key::Enter);
+        harness.run();
+        harness.run();
+        assert_eq!(inputs.borrow().page, 1);
+
+        assert_eq!(
+            press_button(&mut harness, &WizardButton::Back.localize()),
+            None
+        );
+        harness.run();
+        assert_eq!(inputs.borrow().page, 0);
+
+        // the name is still there to finish with
+        harness.press(Key::Enter);
+        harness.run();
+        harness.press(Key::Enter);
+        assert_eq!(reply(&mut harness), Some(Some("Ada".to_owned())));
+    }
+
+    #[test]
+    fn cancel_replies_none() {
+        let (mut harness, _) = open(true);
+        assert_eq!(
+            press_button(&mut harness, &WizardButton::Cancel.localize()),
+            Some(None)
+        );
+    }
+
+    #[test]
+    fn escape_replies_none() {
+        let (mut harness, _) = open(true);
+        harness.press(Key::Escape);
+        assert_eq!(reply(&mut harness), Some(None));
+    }
+
+    #[test]
+    fn the_close_button_replies_none() {
+        let (mut harness, _) = open(true);
+        assert_eq!(press_button(&mut harness, &translate("close")), Some(None));
+    }
+}